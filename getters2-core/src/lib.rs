@@ -0,0 +1,281 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+//! Runtime support for code generated by the `getters2` derive macro.
+//!
+//! The derive macro itself cannot export types or traits, so everything generated code needs
+//! to name at runtime lives here.
+//!
+//! # Lenses and Prisms
+//!
+//! With `#[getters(lens)]`, each struct field gets an associated constant implementing
+//! [`Lens`], and each enum variant field gets one implementing [`Prism`]. These let generic
+//! code address fields as values instead of closures.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! use getters2_core::Lens;
+//!
+//! #[derive(Getters)]
+//! #[getters(lens)]
+//! struct Vector3 {
+//!   x: f32,
+//!   y: f32,
+//!   z: f32,
+//! }
+//!
+//! fn double<S>(source: &mut S, lens: impl Lens<S, f32>) {
+//!   *lens.get_mut(source) *= 2.0;
+//! }
+//!
+//! let mut v = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+//! double(&mut v, Vector3::Y);
+//! assert_eq!(Vector3::Y.get(&v), &4.0);
+//! ```
+//!
+//! # Field Traits
+//!
+//! With `#[getters(has_field)]`, each struct field gets implementations of [`HasField`] and,
+//! if it has a mutable getter, [`HasFieldMut`], for the marker type [`field!(name)`](field).
+//! These let generic code require any type with a field of a given name and type.
+
+#![no_std]
+#![deny(missing_docs)]
+
+use core::fmt::{Debug, Display, Formatter, Result as FmtResult};
+
+/// A first-class reference to a field of `S` with type `T`
+pub trait Lens<S: ?Sized, T: ?Sized> {
+    /// Return an immutable reference to the field of `source`
+    fn get<'a>(&self, source: &'a S) -> &'a T;
+    /// Return a mutable reference to the field of `source`
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut T;
+}
+
+/// A first-class reference to a field of `S` with type `T` which is only present for some
+/// values of `S`, like a field of an enum variant
+pub trait Prism<S: ?Sized, T: ?Sized> {
+    /// Return an immutable reference to the field of `source`, if it is present
+    fn get<'a>(&self, source: &'a S) -> Option<&'a T>;
+    /// Return a mutable reference to the field of `source`, if it is present
+    fn get_mut<'a>(&self, source: &'a mut S) -> Option<&'a mut T>;
+}
+
+/// A [`Lens`] implemented by a pair of accessor functions. This is the type of the lens
+/// constants generated for struct fields.
+pub struct FieldLens<S: ?Sized, T: ?Sized> {
+    name: &'static str,
+    get: fn(&S) -> &T,
+    get_mut: fn(&mut S) -> &mut T,
+}
+
+impl<S: ?Sized, T: ?Sized> FieldLens<S, T> {
+    /// Create a new lens from the name of the field and its accessors
+    pub const fn new(name: &'static str, get: fn(&S) -> &T, get_mut: fn(&mut S) -> &mut T) -> Self {
+        Self { name, get, get_mut }
+    }
+
+    /// The name of the field this lens refers to
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<S: ?Sized, T: ?Sized> Lens<S, T> for FieldLens<S, T> {
+    #[inline(always)]
+    fn get<'a>(&self, source: &'a S) -> &'a T {
+        (self.get)(source)
+    }
+
+    #[inline(always)]
+    fn get_mut<'a>(&self, source: &'a mut S) -> &'a mut T {
+        (self.get_mut)(source)
+    }
+}
+
+impl<S: ?Sized, T: ?Sized> Clone for FieldLens<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized, T: ?Sized> Copy for FieldLens<S, T> {}
+
+impl<S: ?Sized, T: ?Sized> Debug for FieldLens<S, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("FieldLens")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// A [`Prism`] implemented by a pair of accessor functions. This is the type of the prism
+/// constants generated for enum variant fields.
+pub struct VariantPrism<S: ?Sized, T: ?Sized> {
+    name: &'static str,
+    get: fn(&S) -> Option<&T>,
+    get_mut: fn(&mut S) -> Option<&mut T>,
+}
+
+impl<S: ?Sized, T: ?Sized> VariantPrism<S, T> {
+    /// Create a new prism from the name of the variant field and its accessors
+    pub const fn new(
+        name: &'static str,
+        get: fn(&S) -> Option<&T>,
+        get_mut: fn(&mut S) -> Option<&mut T>,
+    ) -> Self {
+        Self { name, get, get_mut }
+    }
+
+    /// The name of the variant field this prism refers to
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+}
+
+impl<S: ?Sized, T: ?Sized> Prism<S, T> for VariantPrism<S, T> {
+    #[inline(always)]
+    fn get<'a>(&self, source: &'a S) -> Option<&'a T> {
+        (self.get)(source)
+    }
+
+    #[inline(always)]
+    fn get_mut<'a>(&self, source: &'a mut S) -> Option<&'a mut T> {
+        (self.get_mut)(source)
+    }
+}
+
+impl<S: ?Sized, T: ?Sized> Clone for VariantPrism<S, T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<S: ?Sized, T: ?Sized> Copy for VariantPrism<S, T> {}
+
+impl<S: ?Sized, T: ?Sized> Debug for VariantPrism<S, T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.debug_struct("VariantPrism")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+/// The error returned when parsing the field enum generated with `#[getters(field_enum)]`
+/// from a string which is not the name of a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct UnknownField;
+
+impl Display for UnknownField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        f.write_str("unknown field name")
+    }
+}
+
+impl core::error::Error for UnknownField {}
+
+/// The error returned by the enum accessors generated with
+/// `#[getters(enum_return = "result")]` when the enum is not the variant of the field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct VariantMismatch {
+    expected: &'static str,
+    actual: &'static str,
+}
+
+impl VariantMismatch {
+    /// Create a new error from the name of the variant of the field and the name of the
+    /// active variant
+    pub const fn new(expected: &'static str, actual: &'static str) -> Self {
+        Self { expected, actual }
+    }
+
+    /// The name of the variant of the field
+    pub const fn expected(&self) -> &'static str {
+        self.expected
+    }
+
+    /// The name of the active variant
+    pub const fn actual(&self) -> &'static str {
+        self.actual
+    }
+}
+
+impl Display for VariantMismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> FmtResult {
+        write!(
+            f,
+            "expected variant `{}`, found `{}`",
+            self.expected, self.actual
+        )
+    }
+}
+
+impl core::error::Error for VariantMismatch {}
+
+/// A type with a field named by the marker type `F`, usually [`field!(name)`](field), of
+/// type `Type`. This is implemented for each field of a struct deriving `Getters` with
+/// `#[getters(has_field)]`, so generic code can require a field by name and type.
+pub trait HasField<F> {
+    /// The type of the field
+    type Type: ?Sized;
+
+    /// Return an immutable reference to the field
+    fn field(&self) -> &Self::Type;
+}
+
+/// A type with a field named by the marker type `F` which can be mutated. This is implemented
+/// for each field with a mutable getter of a struct deriving `Getters` with
+/// `#[getters(has_field)]`.
+pub trait HasFieldMut<F>: HasField<F> {
+    /// Return a mutable reference to the field
+    fn field_mut(&mut self) -> &mut Self::Type;
+}
+
+/// The marker type naming a field in [`HasField`], identified by a hash of the field's name.
+/// Use [`field!`] to name this type.
+pub enum Field<const ID: u128> {}
+
+/// Return the identifier of a field name in [`Field`], its 128-bit FNV-1a hash
+pub const fn field_id(name: &str) -> u128 {
+    const OFFSET: u128 = 0x6c62272e07bb014262b821756295c58d;
+    const PRIME: u128 = 0x0000000001000000000000000000013b;
+
+    let bytes = name.as_bytes();
+    let mut hash = OFFSET;
+    let mut i = 0;
+
+    while i < bytes.len() {
+        hash ^= bytes[i] as u128;
+        hash = hash.wrapping_mul(PRIME);
+        i += 1;
+    }
+
+    hash
+}
+
+/// Name the marker type of a field in [`HasField`] and [`HasFieldMut`]
+///
+/// ```rust
+/// # use getters2::Getters;
+/// use getters2_core::{field, HasField};
+///
+/// #[derive(Getters)]
+/// #[getters(has_field)]
+/// struct User {
+///   id: u64,
+///   name: String,
+/// }
+///
+/// fn id<T: HasField<field!(id), Type = u64>>(value: &T) -> u64 {
+///   *value.field()
+/// }
+///
+/// let user = User { id: 42, name: "foo".to_string() };
+/// assert_eq!(id(&user), 42);
+/// ```
+#[macro_export]
+macro_rules! field {
+    ($name:ident) => {
+        $crate::Field<{ $crate::field_id(::core::stringify!($name)) }>
+    };
+}
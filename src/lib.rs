@@ -33,7 +33,8 @@
 //! ### Tuple Structs
 //!
 //! Tuple structs are automatically named from first to last (there is technically a
-//! limit of 20 elements, but if you hit it, you may just want to refactor).
+//! limit of 21 elements, or 20 for tuple enum variants, but if you hit it, you may just want
+//! to refactor).
 //!
 //! ```rust
 //! # use getters2::Getters;
@@ -165,6 +166,454 @@
 //! assert_eq!(v.z_ref(), &5.0);
 //! ```
 //!
+//! ### Renaming Getters
+//!
+//! Getters are named after their field, or after the ordinal of tuple fields. A field can
+//! choose a different name with `rename`. Two fields whose getters would have the same name
+//! are reported as an error, and `rename` resolves the collision.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! struct Vector3 {
+//!   #[getters(rename = "x_axis")]
+//!   x: f32,
+//!   y: f32,
+//!   z: f32,
+//! }
+//!
+//! let v = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+//! assert_eq!(v.x_axis_ref(), &1.0);
+//! ```
+//!
+//! ### Generic Structs
+//!
+//! Clone and deref getters of fields whose type names a type parameter are bounded on the
+//! field's type (e.g. `where T: Clone`), so the struct itself does not need the bound. The
+//! `bound` attribute on the struct or a field replaces the inferred bound with its own
+//! where clause, which may be empty.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! # use std::rc::Rc;
+//! #[derive(Getters)]
+//! #[getters(clone)]
+//! struct Shared<T> {
+//!   value: T,
+//!   #[getters(bound = "")]
+//!   shared: Rc<T>,
+//! }
+//!
+//! let s = Shared { value: 1.0, shared: Rc::new(2.0) };
+//! assert_eq!(s.value_clone(), 1.0);
+//! assert_eq!(*s.shared_clone(), 2.0);
+//! ```
+//!
+//! ### Reference Fields
+//!
+//! The immutable getter of a field which is itself a reference returns a reference to the
+//! reference, tied to the lifetime of `self`. With `reborrow`, fields whose type is a shared
+//! reference also get a getter named after the field which copies the reference out with its
+//! original lifetime, and fields whose type is a mutable reference get one which reborrows it
+//! mutably. Fields of other types are unaffected.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(reborrow)]
+//! struct View<'a> {
+//!   data: &'a [u8],
+//!   out: &'a mut Vec<u8>,
+//! }
+//!
+//! let mut out = Vec::new();
+//! let data = {
+//!   let mut view = View { data: &[1, 2, 3], out: &mut out };
+//!   view.out().push(4);
+//!   view.data()
+//! };
+//! assert_eq!(data, &[1, 2, 3]);
+//! assert_eq!(out, vec![4]);
+//! ```
+//!
+//! ### Interior Mutability
+//!
+//! With `interior`, fields of interior mutability types get accessors which go through
+//! `&self`, detected from the name of the field's type:
+//!
+//! * `Cell<T>` - `x_get` and `x_set`
+//! * `RefCell<T>` - `borrow_x` and `borrow_mut_x`
+//! * `Mutex<T>` - `lock_x`
+//! * `RwLock<T>` - `read_x` and `write_x`
+//! * Atomics - `load_x` and `store_x`, using the `ordering` of the field or struct, one of
+//!   `Relaxed`, `AcqRel` or `SeqCst` (the default)
+//!
+//! Types are only detected by name if their path is unqualified or starts with `std` or
+//! `core`, since types like `parking_lot::Mutex` have different signatures. A field whose type
+//! is an alias of one of these, or a type from another crate with the same signatures, can
+//! name it with `interior_type`, like `#[getters(interior_type = "AtomicU64")]`. Fields of
+//! other types are unaffected.
+//!
+//! With the `async` feature, fields of async lock types get `async` accessors returning their
+//! guards: `lock_x` for mutexes, and `read_x` and `write_x` for read-write locks. Since these
+//! types are not part of `std`, their paths are configured on the struct with
+//! `#[getters(async_locks(mutex(tokio::sync::Mutex), rw_lock(tokio::sync::RwLock)))]`, and the
+//! lock types only need `async` methods with those names. Field types must be written with the
+//! same path as configured, so `Mutex<T>` only matches a configured `Mutex`, and several paths
+//! can be configured for the same kind of lock.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! use std::{cell::{Cell, RefCell}, sync::atomic::AtomicUsize};
+//!
+//! #[derive(Getters)]
+//! #[getters(interior, ordering = "Relaxed")]
+//! struct Stats {
+//!   count: Cell<u32>,
+//!   names: RefCell<Vec<String>>,
+//!   hits: AtomicUsize,
+//! }
+//!
+//! let stats = Stats {
+//!   count: Cell::new(0),
+//!   names: RefCell::new(vec![]),
+//!   hits: AtomicUsize::new(0),
+//! };
+//! stats.count_set(stats.count_get() + 1);
+//! stats.borrow_mut_names().push("foo".to_string());
+//! stats.store_hits(stats.load_hits() + 1);
+//! assert_eq!(stats.count_get(), 1);
+//! assert_eq!(stats.borrow_names().len(), 1);
+//! assert_eq!(stats.load_hits(), 1);
+//! ```
+//!
+//! ### Field Enums
+//!
+//! With `field_enum`, a struct gets a companion enum with a variant for each field which has
+//! an immutable getter, named in upper camel case. It implements `Display` and `FromStr` with
+//! the names of the fields, and lists all the fields in `ALL`. The `get` method takes one of
+//! its variants and returns an enum of references to the field. If some fields have a mutable
+//! getter, `get_mut` returns an enum of mutable references to them, or `None` for the other
+//! fields. Parsing an unknown name fails with `UnknownField` from the `getters2-core` crate, so
+//! using field enums requires depending on `getters2-core`.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(field_enum = "Vector3Field", mutable)]
+//! struct Vector3 {
+//!   x: f32,
+//!   y: f32,
+//!   z: f32,
+//! }
+//!
+//! let mut v = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+//! let field = "y".parse::<Vector3Field>().unwrap();
+//! assert_eq!(field, Vector3Field::Y);
+//! if let Some(Vector3FieldMut::Y(y)) = v.get_mut(field) {
+//!   *y = 4.0;
+//! }
+//! assert!(matches!(v.get(field), Vector3FieldRef::Y(&4.0)));
+//! ```
+//!
+//! ### Split Borrows
+//!
+//! Mutable getters borrow all of `self`, so only one of them can be used at a time. With
+//! `split`, `split_mut` returns a struct with a mutable reference to each field which has a
+//! mutable getter, and `split` one with an immutable reference to each field which has an
+//! immutable getter. The fields of these views are named after the getters. Enums get
+//! `split_x` and `split_x_mut` methods for each variant instead, returning `None` if the
+//! enum is not the variant.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(split, mutable)]
+//! struct Vector3 {
+//!   x: f32,
+//!   y: f32,
+//!   z: f32,
+//! }
+//!
+//! let mut v = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+//! let Vector3Mut { x, y, z } = v.split_mut();
+//! std::mem::swap(x, y);
+//! *z += 1.0;
+//! let view = v.split();
+//! assert_eq!((view.x, view.y, view.z), (&2.0, &1.0, &4.0));
+//! ```
+//!
+//! ### Bitfields
+//!
+//! Integer fields can be split into bitfields with `bits`, each of which gets a getter named
+//! `name` and a setter named `set_{name}` for the bits in `range`. Values are the smallest
+//! unsigned integer type which fits the range, and setting a value which does not fit is a
+//! debug assertion failure. This works on `repr(packed)` structs as well.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! struct Ipv4Header {
+//!   #[getters(bits(name = "version", range = "4..8"), bits(name = "ihl", range = "0..4"))]
+//!   version_ihl: u8,
+//! }
+//!
+//! let mut header = Ipv4Header { version_ihl: 0x45 };
+//! assert_eq!((header.version(), header.ihl()), (4, 5));
+//! header.set_ihl(6);
+//! assert_eq!(header.version_ihl_ref(), &0x46);
+//! ```
+//!
+//! ### Byte Order
+//!
+//! Byte array fields can be read and written as integers in a given byte order with a flag
+//! like `be_u32` or `le_i16`, which generates a getter named after the field and a setter
+//! named `set_{name}`. The length of the array must match the width of the integer. This
+//! composes with the other accessors, and works on `repr(packed)` structs as well.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! struct Frame {
+//!   #[getters(be_u32)]
+//!   len: [u8; 4],
+//! }
+//!
+//! let mut frame = Frame { len: [0, 0, 1, 2] };
+//! assert_eq!(frame.len(), 0x0102);
+//! frame.set_len(3);
+//! assert_eq!(frame.len_ref(), &[0, 0, 0, 3]);
+//! ```
+//!
+//! ### Packed Structs
+//!
+//! Fields of `repr(packed)` structs may be unaligned, so no references to them can be
+//! created. Instead of `x_ref`, each field of a packed struct gets a `x_get` accessor returning
+//! a copy of the field and a `x_set` accessor replacing it, which read and write the field
+//! without creating a reference. These require the field to be `Copy`, as does `x_deref`,
+//! which is generated the same way with `deref`. Enabling any accessor which needs a reference
+//! to the field, like `mutable`, `lens` or `clone` (since `Clone::clone` takes one), on a packed
+//! struct is an error.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[repr(C, packed)]
+//! struct Header {
+//!   tag: u8,
+//!   length: u32,
+//! }
+//!
+//! let mut header = Header { tag: 1, length: 2 };
+//! header.length_set(3);
+//! assert_eq!((header.tag_get(), header.length_get()), (1, 3));
+//! ```
+//!
+//! ### Pin Projections
+//!
+//! Fields marked `pin` are structurally pinned, and get a `x_pin` method projecting a
+//! `Pin<&mut Self>` to a `Pin<&mut T>` of the field. The other fields get a `x_pin_unpinned`
+//! method returning a plain mutable reference instead. The struct is then only `Unpin` if its
+//! pinned fields are, and it cannot implement `Drop`, which could move a pinned field.
+//! This is only supported on structs.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! use std::future::Future;
+//! use std::pin::Pin;
+//! use std::task::{Context, Poll};
+//!
+//! #[derive(Getters)]
+//! struct Counted<F> {
+//!   #[getters(pin)]
+//!   future: F,
+//!   polls: u32,
+//! }
+//!
+//! impl<F: Future> Future for Counted<F> {
+//!   type Output = F::Output;
+//!
+//!   fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+//!     *self.as_mut().polls_pin_unpinned() += 1;
+//!     self.future_pin().poll(cx)
+//!   }
+//! }
+//! ```
+//!
+//! ### Flattening Nested Structs
+//!
+//! A field whose type also derives `Getters` can be flattened with `flatten`, which generates
+//! accessors on the outer struct delegating to the accessors of the inner type. The
+//! delegating accessors are prefixed with the field name by default, or with `prefix`
+//! (an empty prefix drops it entirely), and forward the arguments of accessors taking them,
+//! like setters. Accessors with generic parameters or a receiver other than a reference, like
+//! `split` and pin projections, are not delegated. Flattening works through any number of
+//! levels and across modules, as long as the types returned by the accessors are nameable from
+//! where the outer struct is defined. The inner type is found through a hidden macro which is
+//! imported along with it, and which is only visible within its crate, so a type from another
+//! crate can never be flattened.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(mutable)]
+//! struct Window {
+//!   width: u32,
+//!   height: u32,
+//! }
+//!
+//! #[derive(Getters)]
+//! struct Config {
+//!   #[getters(flatten)]
+//!   window: Window,
+//!   #[getters(flatten, prefix = "")]
+//!   other: Window,
+//! }
+//!
+//! let mut c = Config {
+//!   window: Window { width: 800, height: 600 },
+//!   other: Window { width: 1, height: 2 },
+//! };
+//! assert_eq!(c.window_width_ref(), &800);
+//! assert_eq!(c.width_ref(), &1);
+//! *c.window_height_mut() = 480;
+//! assert_eq!(c.window_height_ref(), &480);
+//! ```
+//!
+//! ### Lenses
+//!
+//! With `lens`, every field also gets an associated constant implementing the `Lens` trait
+//! from the `getters2-core` crate (enum variant fields get a `Prism` instead, which returns an
+//! `Option`). The constants are named after the field in upper case, and can be skipped for a
+//! field or variant with `skip_lens`. Using lenses requires depending on `getters2-core`.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! use getters2_core::{Lens, Prism};
+//!
+//! #[derive(Getters)]
+//! #[getters(lens)]
+//! struct Vector3 {
+//!   x: f32,
+//!   y: f32,
+//!   z: f32,
+//! }
+//!
+//! #[derive(Getters)]
+//! #[getters(lens)]
+//! enum Animal {
+//!   Dog { name: String },
+//!   Cat(String),
+//! }
+//!
+//! let mut v = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+//! *Vector3::X.get_mut(&mut v) = 4.0;
+//! assert_eq!(Vector3::X.get(&v), &4.0);
+//!
+//! let dog = Animal::Dog { name: "Rover".to_string() };
+//! assert_eq!(Animal::DOG_NAME.get(&dog), Some(&"Rover".to_string()));
+//! assert_eq!(Animal::CAT_FIRST.get(&dog), None);
+//! ```
+//!
+//! ### Field Traits
+//!
+//! With `has_field`, every field also implements the `HasField` trait from the
+//! `getters2-core` crate for the marker type `field!(name)`, and fields with a mutable getter
+//! implement `HasFieldMut` as well. Generic code can then accept any struct with a field of a
+//! given name and type. Fields can opt out with `skip_has_field`. This is only supported on
+//! structs.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! use getters2_core::{field, HasField, HasFieldMut};
+//!
+//! #[derive(Getters)]
+//! #[getters(has_field, mutable)]
+//! struct User {
+//!   id: u64,
+//!   name: String,
+//! }
+//!
+//! #[derive(Getters)]
+//! #[getters(has_field)]
+//! struct Group {
+//!   id: u64,
+//! }
+//!
+//! fn id(value: &impl HasField<field!(id), Type = u64>) -> u64 {
+//!   *value.field()
+//! }
+//!
+//! let mut user = User { id: 1, name: "foo".to_string() };
+//! *HasFieldMut::<field!(name)>::field_mut(&mut user) = "bar".to_string();
+//! assert_eq!(user.name_ref(), "bar");
+//! assert_eq!(id(&user) + id(&Group { id: 2 }), 3);
+//! ```
+//!
+//! ### Layout
+//!
+//! With `layout`, a struct gets an `X_OFFSET` and `X_SIZE` constant for each field, with the
+//! offset of the field from the start of the struct and its size in bytes, and a `FIELD_COUNT`
+//! constant with the number of fields. Tuple fields are named like their accessors, so the
+//! first field of a tuple struct gets `FIRST_OFFSET`. Fields can opt out with `skip_layout`,
+//! and get their constants even if they skip all of their getters with `skip`. This is only
+//! supported on structs, and is most useful on `repr(C)` structs shared over FFI.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(layout)]
+//! #[repr(C)]
+//! struct Descriptor {
+//!   flags: u16,
+//!   len: u32,
+//!   addr: u64,
+//! }
+//!
+//! assert_eq!(Descriptor::FIELD_COUNT, 3);
+//! assert_eq!((Descriptor::LEN_OFFSET, Descriptor::LEN_SIZE), (4, 4));
+//! assert_eq!((Descriptor::ADDR_OFFSET, Descriptor::ADDR_SIZE), (8, 8));
+//! ```
+//!
+//! ### Reflection
+//!
+//! With `reflect`, fields can be enumerated and accessed by name at runtime. Fields are named
+//! like their accessors (so tuple fields are `first`, `second`, ..., and enum variant fields
+//! are prefixed with the variant, like `dog_name`). Enums additionally report the name of their
+//! active variant. Fields or variants can be left out with `skip_reflect`. Fields are returned
+//! as `dyn Any`, so the reflection methods of generic types require the type parameters of
+//! reflected fields to be `'static`, and reflected fields cannot borrow for a lifetime
+//! parameter.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(reflect)]
+//! struct Vector3 {
+//!   x: f32,
+//!   y: f32,
+//!   z: f32,
+//! }
+//!
+//! #[derive(Getters)]
+//! #[getters(reflect)]
+//! enum Animal {
+//!   Dog { name: String },
+//!   Cat(String),
+//! }
+//!
+//! let mut v = Vector3 { x: 1.0, y: 2.0, z: 3.0 };
+//! assert_eq!(Vector3::field_names(), &["x", "y", "z"]);
+//! assert_eq!(v.get_field("y").and_then(|y| y.downcast_ref::<f32>()), Some(&2.0));
+//! *v.get_field_mut("z").and_then(|z| z.downcast_mut::<f32>()).unwrap() = 4.0;
+//! assert_eq!(v.z_ref(), &4.0);
+//!
+//! let cat = Animal::Cat("Mittens".to_string());
+//! assert_eq!(cat.variant_name(), "Cat");
+//! assert_eq!(Animal::field_names(), &["dog_name", "cat_first"]);
+//! assert!(cat.get_field("dog_name").is_none());
+//! ```
+//!
 //! ## Enums
 //!
 //! Of course, everything we just saw for structs also works for enums. Because we don't know
@@ -264,22 +713,111 @@
 //!   
 //! }
 //! ```
+//!
+//! ### Variant Getters
+//!
+//! Getters can also be enabled or skipped for all the fields of a single variant. Each level
+//! inherits from the one above it (enum, then variant, then field), and the most specific
+//! level which enables or skips a getter wins. Enabling and skipping the same getter on one
+//! item, or putting getter attributes on a variant without fields, is an error. Attributes
+//! which have no effect, like enabling a getter an enclosing item already enables or skipping
+//! one which is never enabled, produce a warning. A variant can also replace its lowercase
+//! prefix with `rename`.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(mutable)]
+//! enum Animal {
+//!   #[getters(clone, skip_mutable)]
+//!   Dog {
+//!     name: String,
+//!     #[getters(mutable)]
+//!     age: u8
+//!   },
+//!   Cat(String),
+//! }
+//!
+//! let mut dog = Animal::Dog { name: "Rover".to_string(), age: 5 };
+//! assert_eq!(dog.dog_name_clone(), Some("Rover".to_string()));
+//! // No dog_name_mut method!
+//! // dog.dog_name_mut();
+//! *dog.dog_age_mut().unwrap() = 6;
+//! assert_eq!(dog.dog_age_clone(), Some(6));
+//! assert_eq!(dog.cat_first_mut(), None);
+//! ```
+//!
+//! ### Fallible Variant Getters
+//!
+//! Enum getters return `None` when the enum is not the variant of the field. With
+//! `enum_return = "result"`, they return a `Result` instead, whose `VariantMismatch` error
+//! from the `getters2-core` crate records the expected and active variants. This applies to
+//! the `reborrow`, `interior` and `split` accessors of variants as well, but not to the prisms
+//! generated by `lens`, which implement the `Prism` trait. With `unwrap`,
+//! each field with an immutable or mutable getter also gets an `unwrap_x` or `unwrap_x_mut`
+//! getter returning the reference directly, which panics with both variants otherwise.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! use getters2_core::VariantMismatch;
+//!
+//! #[derive(Getters)]
+//! #[getters(enum_return = "result", unwrap, mutable)]
+//! enum Animal {
+//!   Dog { name: String },
+//!   Cat(String),
+//! }
+//!
+//! let mut dog = Animal::Dog { name: "Rover".to_string() };
+//! assert_eq!(dog.dog_name_ref().map(String::as_str), Ok("Rover"));
+//! assert_eq!(dog.cat_first_ref(), Err(VariantMismatch::new("Cat", "Dog")));
+//! *dog.unwrap_dog_name_mut() = "Spot".to_string();
+//! assert_eq!(dog.unwrap_dog_name(), "Spot");
+//! ```
+//!
+//! ### Variant Kinds
+//!
+//! With `kind`, an enum gets a fieldless companion enum named `{Enum}Kind` with the same
+//! variants, which is `Copy`, `Eq` and `Hash`, and a `kind` method returning the kind of the
+//! active variant. The name and declaration index of the active variant are returned by
+//! `variant_name` and `variant_index`, and the names of all variants are in `VARIANT_NAMES`.
+//! This is only supported on enums.
+//!
+//! ```rust
+//! # use getters2::Getters;
+//! #[derive(Getters)]
+//! #[getters(kind)]
+//! enum Animal {
+//!   Dog { name: String },
+//!   Cat(String),
+//! }
+//!
+//! let cat = Animal::Cat("Mittens".to_string());
+//! assert_eq!(cat.kind(), AnimalKind::Cat);
+//! assert_eq!((cat.variant_name(), cat.variant_index()), ("Cat", 1));
+//! assert_eq!(Animal::VARIANT_NAMES, ["Dog", "Cat"]);
+//! ```
 
 #![allow(unused_variables)]
 #![deny(missing_docs)]
 
 use darling::{
     ast::{Data, Fields},
-    util::Flag,
-    FromDeriveInput, FromField, FromVariant,
+    util::{Flag, PathList, SpannedValue},
+    FromDeriveInput, FromField, FromMeta, FromVariant,
 };
 use proc_macro::TokenStream;
-use proc_macro2::{Span, TokenStream as TokenStream2};
-use proc_macro_error::abort;
-use quote::{format_ident, quote, ToTokens};
+use proc_macro2::{Span, TokenStream as TokenStream2, TokenTree};
+use proc_macro_error::{abort, abort_if_dirty, emit_error, proc_macro_error};
+use quote::{format_ident, quote, quote_spanned, IdentFragment, ToTokens};
 use syn::{
-    parse_macro_input, Attribute, DeriveInput, Expr, Generics, Ident, Index, Member, Type,
-    Visibility,
+    braced,
+    parse::{Parse, ParseStream},
+    parse_macro_input, parse_quote,
+    spanned::Spanned,
+    Attribute, Data as SynData, DeriveInput, Expr, ExprLit, ExprRange, FnArg, GenericArgument,
+    GenericParam, Generics, Ident, ImplItem, Index, ItemImpl, Lit, LitInt, LitStr, Member, Pat,
+    Path, PathArguments, RangeLimits, TraitItemFn, Type, TypePath, Visibility, WherePredicate,
 };
 
 #[derive(Debug, FromField)]
@@ -289,8 +827,6 @@ struct GettersField {
     #[allow(unused)]
     vis: Visibility,
     ty: Type,
-    #[allow(unused)]
-    attrs: Vec<Attribute>,
     mutable: Flag,
     deref: Flag,
     clone: Flag,
@@ -298,6 +834,27 @@ struct GettersField {
     skip_mutable: Flag,
     skip_deref: Flag,
     skip_clone: Flag,
+    flatten: Flag,
+    prefix: Option<LitStr>,
+    lens: Flag,
+    skip_lens: Flag,
+    reborrow: Flag,
+    skip_reborrow: Flag,
+    interior: Flag,
+    skip_interior: Flag,
+    has_field: Flag,
+    skip_has_field: Flag,
+    pin: Flag,
+    #[darling(multiple)]
+    bits: Vec<Bits>,
+    #[darling(flatten)]
+    byte_order: ByteOrder,
+    interior_type: Option<LitStr>,
+    ordering: Option<LitStr>,
+    skip_reflect: Flag,
+    skip_layout: Flag,
+    rename: Option<LitStr>,
+    bound: Option<SpannedValue<Vec<WherePredicate>>>,
 }
 
 #[derive(Debug, FromVariant)]
@@ -306,18 +863,21 @@ struct GettersVariant {
     ident: Ident,
     discriminant: Option<Expr>,
     fields: Fields<GettersField>,
-    #[allow(unused)]
-    attrs: Vec<Attribute>,
-    #[allow(unused)]
     mutable: Flag,
-    #[allow(unused)]
     deref: Flag,
-    #[allow(unused)]
     clone: Flag,
+    lens: Flag,
     skip: Flag,
     skip_mutable: Flag,
     skip_deref: Flag,
     skip_clone: Flag,
+    skip_lens: Flag,
+    reborrow: Flag,
+    skip_reborrow: Flag,
+    interior: Flag,
+    skip_interior: Flag,
+    skip_reflect: Flag,
+    rename: Option<LitStr>,
 }
 
 #[derive(Debug, FromDeriveInput)]
@@ -343,53 +903,1556 @@ struct GettersVariant {
         deprecated,
         must_use,
         doc,
-        non_exhaustive
+        non_exhaustive,
+        repr
     )
 )]
 struct GettersInput {
     ident: Ident,
-    #[allow(unused)]
     vis: Visibility,
     generics: Generics,
     data: Data<GettersVariant, GettersField>,
-    #[allow(unused)]
     attrs: Vec<Attribute>,
     mutable: Flag,
     clone: Flag,
     deref: Flag,
+    lens: Flag,
+    reborrow: Flag,
+    interior: Flag,
+    ordering: Option<LitStr>,
+    async_locks: Option<SpannedValue<AsyncLocks>>,
+    reflect: Flag,
+    field_enum: Option<LitStr>,
+    split: Flag,
+    has_field: Flag,
+    layout: Flag,
+    kind: Flag,
+    enum_return: Option<LitStr>,
+    unwrap: Flag,
+    bound: Option<SpannedValue<Vec<WherePredicate>>>,
 }
 
-impl GettersInput {
-    fn method_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
-        let ty = &field.ty;
-        let immutable = !field.skip.is_present();
-        let mutable = (field.mutable.is_present() || self.mutable.is_present())
-            && !field.skip_mutable.is_present();
-        let clone =
-            (field.clone.is_present() || self.clone.is_present()) && !field.skip_clone.is_present();
-        let deref =
-            (field.deref.is_present() || self.deref.is_present()) && !field.skip_deref.is_present();
+/// The kinds of accessors which can be enabled or skipped at each level of the input
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Access {
+    Ref,
+    Mutable,
+    Clone,
+    Deref,
+    Lens,
+    Reborrow,
+    Interior,
+    HasField,
+}
 
-        let (immutable, maybe_mutable, maybe_clone, maybe_deref) =
-            if let Some(ident) = field.ident.as_ref() {
-                let ident_ref = format_ident!("{}_ref", ident);
-                let ident_mut = format_ident!("{}_mut", ident);
-                let ident_clone = format_ident!("{}_clone", ident);
-                let ident_deref = format_ident!("{}_deref", ident);
-                (
-                    immutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return an immutable reference to the field
-                            pub fn #ident_ref(&self) -> &#ty {
-                                &self.#ident
-                            }
-                        })
-                        .unwrap_or_default(),
-                    mutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a mutable reference to the field
+impl Access {
+    const ALL: [Access; 8] = [
+        Access::Ref,
+        Access::Mutable,
+        Access::Clone,
+        Access::Deref,
+        Access::Lens,
+        Access::Reborrow,
+        Access::Interior,
+        Access::HasField,
+    ];
+
+    /// The name of the flag enabling this kind of accessor, if it can be enabled
+    fn enable(&self) -> Option<&'static str> {
+        match self {
+            Access::Ref => None,
+            Access::Mutable => Some("mutable"),
+            Access::Clone => Some("clone"),
+            Access::Deref => Some("deref"),
+            Access::Lens => Some("lens"),
+            Access::Reborrow => Some("reborrow"),
+            Access::Interior => Some("interior"),
+            Access::HasField => Some("has_field"),
+        }
+    }
+
+    /// The names of the methods or constants generated for this kind of accessor of `field`,
+    /// whose accessors are named `base`
+    fn generated(&self, base: &str, interior: Option<Interior>) -> Vec<String> {
+        match self {
+            Access::Ref => vec![format!("{}_ref", base)],
+            Access::Mutable => vec![format!("{}_mut", base)],
+            Access::Clone => vec![format!("{}_clone", base)],
+            Access::Deref => vec![format!("{}_deref", base)],
+            Access::Lens => vec![base.to_ascii_uppercase()],
+            Access::Reborrow => vec![base.to_string()],
+            Access::Interior => interior
+                .map(|i| i.names(base).iter().map(|n| n.to_string()).collect())
+                .unwrap_or_default(),
+            Access::HasField => vec![format!("HasField<field!({})>", base)],
+        }
+    }
+
+    /// The name of the flag skipping this kind of accessor
+    fn skip(&self) -> &'static str {
+        match self {
+            Access::Ref => "skip",
+            Access::Mutable => "skip_mutable",
+            Access::Clone => "skip_clone",
+            Access::Deref => "skip_deref",
+            Access::Lens => "skip_lens",
+            Access::Reborrow => "skip_reborrow",
+            Access::Interior => "skip_interior",
+            Access::HasField => "skip_has_field",
+        }
+    }
+}
+
+/// The level of the input an accessor is enabled at
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Level {
+    Container,
+    Variant,
+    Field,
+}
+
+/// The interior mutability types which get accessors with `interior`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum InteriorKind {
+    Cell,
+    RefCell,
+    Mutex,
+    RwLock,
+    Atomic,
+    AsyncMutex,
+    AsyncRwLock,
+}
+
+impl InteriorKind {
+    /// Whether the accessors of this type are `async`
+    fn is_async(&self) -> bool {
+        matches!(self, InteriorKind::AsyncMutex | InteriorKind::AsyncRwLock)
+    }
+}
+
+/// The integer a byte array field is read and written as, selected by a flag naming its byte
+/// order and type like `be_u32`
+#[derive(Debug, Default, FromMeta)]
+struct ByteOrder {
+    be_u16: Flag,
+    be_u32: Flag,
+    be_u64: Flag,
+    be_u128: Flag,
+    be_i16: Flag,
+    be_i32: Flag,
+    be_i64: Flag,
+    be_i128: Flag,
+    le_u16: Flag,
+    le_u32: Flag,
+    le_u64: Flag,
+    le_u128: Flag,
+    le_i16: Flag,
+    le_i32: Flag,
+    le_i64: Flag,
+    le_i128: Flag,
+}
+
+impl ByteOrder {
+    /// The flags which are present, by name
+    fn selected(&self) -> Vec<(&'static str, Flag)> {
+        [
+            ("be_u16", self.be_u16),
+            ("be_u32", self.be_u32),
+            ("be_u64", self.be_u64),
+            ("be_u128", self.be_u128),
+            ("be_i16", self.be_i16),
+            ("be_i32", self.be_i32),
+            ("be_i64", self.be_i64),
+            ("be_i128", self.be_i128),
+            ("le_u16", self.le_u16),
+            ("le_u32", self.le_u32),
+            ("le_u64", self.le_u64),
+            ("le_u128", self.le_u128),
+            ("le_i16", self.le_i16),
+            ("le_i32", self.le_i32),
+            ("le_i64", self.le_i64),
+            ("le_i128", self.le_i128),
+        ]
+        .into_iter()
+        .filter(|(_, f)| f.is_present())
+        .collect()
+    }
+}
+
+/// A bitfield of an integer field, configured by `bits`, with accessors named `name` and
+/// `set_{name}` for the bits in `range`
+#[derive(Debug, FromMeta)]
+struct Bits {
+    name: LitStr,
+    range: LitStr,
+}
+
+impl Bits {
+    /// The name of the getter, spanned at the `name` attribute
+    fn name(&self) -> Ident {
+        let mut name = self
+            .name
+            .parse::<Ident>()
+            .unwrap_or_else(|_| abort!(self.name, "`name` must be a valid identifier"));
+        name.set_span(self.name.span());
+        name
+    }
+
+    /// The start and exclusive end of the bits in a field `width` bits wide
+    fn range(&self, width: u32) -> (u32, u32) {
+        let bound = |expr: Option<&Expr>| match expr {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Int(int), ..
+            })) => int.base10_parse::<u32>().ok(),
+            _ => None,
+        };
+        let range = self.range.parse::<ExprRange>().ok();
+        let bounds = range.as_ref().and_then(|r| {
+            let start = bound(r.start.as_deref())?;
+            let end = bound(r.end.as_deref())?;
+
+            match r.limits {
+                RangeLimits::HalfOpen(_) => Some((start, end)),
+                RangeLimits::Closed(_) => Some((start, end + 1)),
+            }
+        });
+
+        match bounds {
+            Some((start, end)) if start < end && end <= width => (start, end),
+            Some(_) => abort!(
+                self.range,
+                "`range` must be a non-empty range within the {} bits of the field",
+                width
+            ),
+            None => abort!(
+                self.range,
+                "`range` must be a range of bits like \"0..4\" or \"0..=3\""
+            ),
+        }
+    }
+}
+
+/// The paths of the async lock types which get accessors with `interior`, configured by
+/// `async_locks`. Async lock types are not part of `std`, so none are recognized by default.
+#[derive(Debug, Default, FromMeta)]
+struct AsyncLocks {
+    #[darling(default)]
+    mutex: PathList,
+    #[darling(default)]
+    rw_lock: PathList,
+}
+
+impl AsyncLocks {
+    /// The kind of async lock `path` refers to, if it is one of the configured paths. Paths
+    /// match only if they name the same segments, so a configured `tokio::sync::Mutex` does
+    /// not match a field of type `Mutex<T>`, which may be a `std::sync::Mutex` imported under
+    /// the same name, and a configured `Mutex` does not match `std::sync::Mutex<T>`.
+    fn kind(&self, path: &Path) -> Option<InteriorKind> {
+        let idents = |p: &Path| {
+            p.segments
+                .iter()
+                .map(|s| s.ident.clone())
+                .collect::<Vec<_>>()
+        };
+        let field = idents(path);
+        let matches = |paths: &PathList| paths.iter().any(|p| idents(p) == field);
+
+        if matches(&self.mutex) {
+            Some(InteriorKind::AsyncMutex)
+        } else if matches(&self.rw_lock) {
+            Some(InteriorKind::AsyncRwLock)
+        } else {
+            None
+        }
+    }
+}
+
+/// The interior mutability type of a field, and the type of the value it holds
+#[derive(Debug, Clone)]
+struct Interior {
+    kind: InteriorKind,
+    value: Type,
+}
+
+impl Interior {
+    /// Detect the interior mutability type of a field from the name of its type if it is
+    /// unqualified or in `std` or `core`, or from the name given by `interior_type` for other
+    /// types which behave like one. Async lock types are detected from the paths configured by
+    /// `async_locks`.
+    fn detect(
+        ty: &Type,
+        interior_type: Option<&LitStr>,
+        async_locks: Option<&AsyncLocks>,
+    ) -> Option<Self> {
+        let path = match ty {
+            Type::Path(TypePath { path, .. }) => Some(path),
+            _ => None,
+        };
+        let segment = path.and_then(|p| p.segments.last());
+        let argument = segment.and_then(|s| match &s.arguments {
+            PathArguments::AngleBracketed(arguments) => {
+                arguments.args.iter().find_map(|a| match a {
+                    GenericArgument::Type(ty) => Some(ty.clone()),
+                    _ => None,
+                })
+            }
+            _ => None,
+        });
+
+        if let Some(kind) = path.zip(async_locks).and_then(|(p, l)| l.kind(p)) {
+            return argument.map(|value| Self { kind, value });
+        }
+
+        // NOTE: Types from other crates, like `parking_lot::Mutex`, share names with the `std`
+        // types but not their signatures, so only unqualified and `std` or `core` paths are
+        // detected by name
+        let std_path = path.is_some_and(|p| {
+            p.segments.len() == 1
+                || p.segments
+                    .first()
+                    .is_some_and(|s| s.ident == "std" || s.ident == "core")
+        });
+        let name = interior_type
+            .map(|t| t.value())
+            .or_else(|| segment.filter(|_| std_path).map(|s| s.ident.to_string()))?;
+
+        let interior = match name.as_str() {
+            "Cell" => argument.map(|value| (InteriorKind::Cell, value)),
+            "RefCell" => argument.map(|value| (InteriorKind::RefCell, value)),
+            "Mutex" => argument.map(|value| (InteriorKind::Mutex, value)),
+            "RwLock" => argument.map(|value| (InteriorKind::RwLock, value)),
+            "AtomicPtr" => argument.map(|value| (InteriorKind::Atomic, parse_quote!(*mut #value))),
+            "AtomicBool" => Some((InteriorKind::Atomic, parse_quote!(bool))),
+            "AtomicI8" | "AtomicI16" | "AtomicI32" | "AtomicI64" | "AtomicIsize" | "AtomicU8"
+            | "AtomicU16" | "AtomicU32" | "AtomicU64" | "AtomicUsize" => Some((
+                InteriorKind::Atomic,
+                Type::Path(TypePath {
+                    qself: None,
+                    path: Ident::new(&name["Atomic".len()..].to_ascii_lowercase(), ty.span())
+                        .into(),
+                }),
+            )),
+            _ => None,
+        };
+
+        match (interior, interior_type) {
+            (Some((kind, value)), _) => Some(Self { kind, value }),
+            (None, None) => None,
+            (None, Some(interior_type)) => abort!(
+                interior_type,
+                "`interior_type` must name a `Cell`, `RefCell`, `Mutex`, `RwLock` or atomic type, \
+                 with a type argument for all but atomic integers and `AtomicBool`"
+            ),
+        }
+    }
+
+    /// The names of the accessors of a field of this type, whose accessors are named `base`.
+    /// The names take the span of `base` if it is an identifier.
+    fn names(&self, base: impl IdentFragment) -> Vec<Ident> {
+        match self.kind {
+            InteriorKind::Cell => {
+                vec![format_ident!("{}_get", base), format_ident!("{}_set", base)]
+            }
+            InteriorKind::RefCell => vec![
+                format_ident!("borrow_{}", base),
+                format_ident!("borrow_mut_{}", base),
+            ],
+            InteriorKind::Mutex | InteriorKind::AsyncMutex => vec![format_ident!("lock_{}", base)],
+            InteriorKind::RwLock | InteriorKind::AsyncRwLock => vec![
+                format_ident!("read_{}", base),
+                format_ident!("write_{}", base),
+            ],
+            InteriorKind::Atomic => vec![
+                format_ident!("load_{}", base),
+                format_ident!("store_{}", base),
+            ],
+        }
+    }
+
+    /// The accessors of a field of type `ty` which has this interior mutability type, in the
+    /// same order as `names`. Each is the extra arguments after `&self`, the return type, if
+    /// any, its documentation and its body, which accesses the field through `field`.
+    fn methods(
+        &self,
+        ty: &Type,
+        (load, store): (TokenStream2, TokenStream2),
+    ) -> Vec<(
+        TokenStream2,
+        Option<TokenStream2>,
+        &'static str,
+        TokenStream2,
+    )> {
+        let value = &self.value;
+        let span = Span::call_site().located_at(ty.span());
+
+        match self.kind {
+            InteriorKind::Cell => vec![
+                (
+                    quote!(),
+                    Some(quote!(#value)),
+                    "Return a copy of the value in the field",
+                    quote_spanned!(span=> <#ty>::get(field)),
+                ),
+                (
+                    quote!(, value: #value),
+                    None,
+                    "Set the value in the field",
+                    quote_spanned!(span=> <#ty>::set(field, value)),
+                ),
+            ],
+            InteriorKind::RefCell => vec![
+                (
+                    quote!(),
+                    Some(quote!(::core::cell::Ref<'_, #value>)),
+                    "Immutably borrow the value in the field",
+                    quote_spanned!(span=> <#ty>::borrow(field)),
+                ),
+                (
+                    quote!(),
+                    Some(quote!(::core::cell::RefMut<'_, #value>)),
+                    "Mutably borrow the value in the field",
+                    quote_spanned!(span=> <#ty>::borrow_mut(field)),
+                ),
+            ],
+            InteriorKind::Mutex => vec![(
+                quote!(),
+                Some(quote!(::std::sync::LockResult<::std::sync::MutexGuard<'_, #value>>)),
+                "Lock the mutex in the field",
+                quote_spanned!(span=> <#ty>::lock(field)),
+            )],
+            InteriorKind::RwLock => vec![
+                (
+                    quote!(),
+                    Some(quote!(
+                        ::std::sync::LockResult<::std::sync::RwLockReadGuard<'_, #value>>
+                    )),
+                    "Lock the lock in the field for reading",
+                    quote_spanned!(span=> <#ty>::read(field)),
+                ),
+                (
+                    quote!(),
+                    Some(quote!(
+                        ::std::sync::LockResult<::std::sync::RwLockWriteGuard<'_, #value>>
+                    )),
+                    "Lock the lock in the field for writing",
+                    quote_spanned!(span=> <#ty>::write(field)),
+                ),
+            ],
+            InteriorKind::Atomic => vec![
+                (
+                    quote!(),
+                    Some(quote!(#value)),
+                    "Load the value of the atomic in the field",
+                    quote_spanned! {span=>
+                        <#ty>::load(field, ::core::sync::atomic::Ordering::#load)
+                    },
+                ),
+                (
+                    quote!(, value: #value),
+                    None,
+                    "Store a value in the atomic in the field",
+                    quote_spanned! {span=>
+                        <#ty>::store(field, value, ::core::sync::atomic::Ordering::#store)
+                    },
+                ),
+            ],
+            InteriorKind::AsyncMutex => vec![(
+                quote!(),
+                Some(quote!(impl ::core::ops::DerefMut<Target = #value> + '_)),
+                "Lock the mutex in the field",
+                quote_spanned!(span=> <#ty>::lock(field).await),
+            )],
+            InteriorKind::AsyncRwLock => vec![
+                (
+                    quote!(),
+                    Some(quote!(impl ::core::ops::Deref<Target = #value> + '_)),
+                    "Lock the lock in the field for reading",
+                    quote_spanned!(span=> <#ty>::read(field).await),
+                ),
+                (
+                    quote!(),
+                    Some(quote!(impl ::core::ops::DerefMut<Target = #value> + '_)),
+                    "Lock the lock in the field for writing",
+                    quote_spanned!(span=> <#ty>::write(field).await),
+                ),
+            ],
+        }
+    }
+}
+
+impl GettersField {
+    /// The name accessors for this field are named after: its `rename`, its identifier, or
+    /// the ordinal of its position for tuple fields. The name is spanned at the field, so
+    /// navigating from an accessor leads to its field.
+    fn name(&self, index: usize, max: usize) -> Ident {
+        let mut name = if let Some(rename) = self.rename.as_ref() {
+            rename
+                .parse()
+                .unwrap_or_else(|_| abort!(rename, "`rename` must be a valid identifier"))
+        } else if let Some(ident) = self.ident.as_ref() {
+            ident.clone()
+        } else {
+            method_name(index, max)
+        };
+
+        name.set_span(self.span());
+        name
+    }
+
+    /// The span of generated accessor names: the field's identifier, or its type for tuple
+    /// fields, which have no identifier
+    fn span(&self) -> Span {
+        self.ident
+            .as_ref()
+            .map(|i| i.span())
+            .unwrap_or_else(|| self.ty.span())
+    }
+
+    /// Describe this field in diagnostics, qualified by its variant if it has one
+    fn describe(&self, index: usize, variant: Option<&GettersVariant>) -> String {
+        let name = self
+            .ident
+            .as_ref()
+            .map(|i| i.to_string())
+            .unwrap_or_else(|| index.to_string());
+
+        match variant {
+            Some(v) => format!("field `{}::{}`", v.ident, name),
+            None => format!("field `{}`", name),
+        }
+    }
+
+    /// The flags with which this field enables and skips a kind of accessor
+    fn flags(&self, access: Access) -> (Flag, Flag) {
+        match access {
+            Access::Ref => (Flag::default(), self.skip),
+            Access::Mutable => (self.mutable, self.skip_mutable),
+            Access::Clone => (self.clone, self.skip_clone),
+            Access::Deref => (self.deref, self.skip_deref),
+            Access::Lens => (self.lens, self.skip_lens),
+            Access::Reborrow => (self.reborrow, self.skip_reborrow),
+            Access::Interior => (self.interior, self.skip_interior),
+            Access::HasField => (self.has_field, self.skip_has_field),
+        }
+    }
+}
+
+impl GettersVariant {
+    /// The prefix of the accessors for fields of this variant: its `rename`, or its
+    /// identifier in lowercase
+    fn prefix(&self) -> String {
+        if let Some(rename) = self.rename.as_ref() {
+            rename
+                .parse::<Ident>()
+                .unwrap_or_else(|_| abort!(rename, "`rename` must be a valid identifier"))
+                .to_string()
+        } else {
+            self.ident.to_string().to_ascii_lowercase()
+        }
+    }
+
+    /// The flags with which this variant enables and skips a kind of accessor for its fields
+    fn flags(&self, access: Access) -> (Flag, Flag) {
+        match access {
+            Access::Ref => (Flag::default(), self.skip),
+            Access::Mutable => (self.mutable, self.skip_mutable),
+            Access::Clone => (self.clone, self.skip_clone),
+            Access::Deref => (self.deref, self.skip_deref),
+            Access::Lens => (self.lens, self.skip_lens),
+            Access::Reborrow => (self.reborrow, self.skip_reborrow),
+            Access::Interior => (self.interior, self.skip_interior),
+            // NOTE: Field traits are only implemented for structs
+            Access::HasField => (Flag::default(), Flag::default()),
+        }
+    }
+}
+
+impl GettersInput {
+    /// Whether the container enables a kind of accessor for all its fields
+    fn flags(&self, access: Access) -> bool {
+        match access {
+            Access::Ref => true,
+            Access::Mutable => self.mutable.is_present(),
+            Access::Clone => self.clone.is_present(),
+            Access::Deref => self.deref.is_present(),
+            Access::Lens => self.lens.is_present(),
+            Access::Reborrow => self.reborrow.is_present(),
+            Access::Interior => self.interior.is_present(),
+            Access::HasField => self.has_field.is_present(),
+        }
+    }
+
+    /// The level a kind of accessor for a field is enabled at, or `None` if it is not
+    /// generated. Each level (container, variant, field) inherits from the level above it, and
+    /// the most specific level which enables or skips the accessor wins.
+    fn access(
+        &self,
+        access: Access,
+        variant: Option<&GettersVariant>,
+        field: &GettersField,
+    ) -> Option<Level> {
+        let (enable, skip) = field.flags(access);
+
+        if skip.is_present() {
+            None
+        } else if enable.is_present() {
+            Some(Level::Field)
+        } else {
+            self.inherited(access, variant)
+        }
+    }
+
+    /// Whether a kind of accessor is generated for a field. Reborrowing accessors are only
+    /// generated for fields whose type is a reference, and interior mutability accessors for
+    /// fields whose type is an interior mutability type.
+    fn generates(
+        &self,
+        access: Access,
+        variant: Option<&GettersVariant>,
+        field: &GettersField,
+    ) -> bool {
+        self.access(access, variant, field).is_some()
+            && match access {
+                Access::Reborrow => matches!(field.ty, Type::Reference(_)),
+                Access::Interior => self.interior(field).is_some(),
+                _ => true,
+            }
+    }
+
+    /// The interior mutability type of a field, if it has one
+    fn interior(&self, field: &GettersField) -> Option<Interior> {
+        Interior::detect(
+            &field.ty,
+            field.interior_type.as_ref(),
+            self.async_locks.as_deref(),
+        )
+    }
+
+    /// Whether enum accessors return a `Result` rather than an `Option`, from `enum_return`
+    fn result(&self) -> bool {
+        match self.enum_return.as_ref().map(|r| r.value()).as_deref() {
+            None | Some("option") => false,
+            Some("result") => true,
+            Some(_) => abort!(
+                self.enum_return,
+                "`enum_return` must be one of `option` or `result`"
+            ),
+        }
+    }
+
+    /// The orderings of the loads and stores of an atomic field, from the `ordering` of the
+    /// field or else of the container
+    fn ordering(&self, field: &GettersField) -> (TokenStream2, TokenStream2) {
+        atomic_ordering(field.ordering.as_ref().or(self.ordering.as_ref()))
+    }
+
+    /// The level a kind of accessor is enabled at for the fields of a variant (or of the
+    /// struct, if `variant` is `None`) before any field-level flags are applied
+    fn inherited(&self, access: Access, variant: Option<&GettersVariant>) -> Option<Level> {
+        let level = self.flags(access).then_some(Level::Container);
+
+        match variant.map(|v| v.flags(access)) {
+            Some((_, skip)) if skip.is_present() => None,
+            Some((enable, _)) if enable.is_present() => Some(Level::Variant),
+            _ => level,
+        }
+    }
+
+    /// The where clause of an accessor of `field` which requires its type to implement
+    /// `bound`. A `bound` attribute on the field, or else on the container, replaces the
+    /// inferred bound, which is only needed for fields whose type names a type parameter.
+    fn bound(&self, field: &GettersField, bound: TokenStream2) -> TokenStream2 {
+        let ty = &field.ty;
+
+        if let Some(predicates) = field.bound.as_ref().or(self.bound.as_ref()) {
+            if predicates.is_empty() {
+                TokenStream2::new()
+            } else {
+                let predicates = predicates.iter();
+                quote!(where #(#predicates),*)
+            }
+        } else if mentions_type_params(ty.to_token_stream(), &self.generics) {
+            quote!(where #ty: #bound)
+        } else {
+            TokenStream2::new()
+        }
+    }
+
+    /// Whether any accessor of `field` has the where clause from `bound`: its clone and deref
+    /// getters, or all its accessors in a `repr(packed)` struct
+    fn uses_bound(&self, variant: Option<&GettersVariant>, field: &GettersField) -> bool {
+        self.access(Access::Clone, variant, field).is_some()
+            || self.access(Access::Deref, variant, field).is_some()
+            || (self.packed() && self.generates(Access::Ref, variant, field))
+    }
+
+    /// Whether this is a `repr(packed)` struct, whose fields may be unaligned and so can only
+    /// be accessed by value
+    fn packed(&self) -> bool {
+        self.attrs
+            .iter()
+            .filter(|a| a.path().is_ident("repr"))
+            .filter_map(|a| a.meta.require_list().ok())
+            .any(|l| {
+                l.tokens
+                    .clone()
+                    .into_iter()
+                    .any(|t| matches!(t, TokenTree::Ident(i) if i == "packed"))
+            })
+    }
+
+    /// Whether this is a struct with structurally pinned fields, which gets pin projections
+    fn pinned(&self) -> bool {
+        self.data
+            .as_ref()
+            .take_struct()
+            .is_some_and(|fields| fields.iter().any(|f| f.pin.is_present()))
+    }
+
+    /// Describe the level an accessor is enabled at, for diagnostics
+    fn origin(&self, level: Option<Level>) -> &'static str {
+        match level {
+            Some(Level::Container) if self.data.is_struct() => "the struct",
+            Some(Level::Container) => "the enum",
+            Some(Level::Variant) => "the variant",
+            Some(Level::Field) => "the field",
+            None => "",
+        }
+    }
+
+    /// Report flags on variants and fields which contradict each other or have no effect.
+    /// Contradictions are errors, and abort expansion. Flags which have no effect are
+    /// returned as warnings to be emitted alongside the generated code.
+    fn validate(&self) -> TokenStream2 {
+        let mut warnings = TokenStream2::new();
+
+        // NOTE: Tuple fields are named while validating, so this must come first
+        match &self.data {
+            Data::Struct(fields) => check_tuple_fields(fields.iter(), NUMERAL_TO_ORDINAL.len() + 1),
+            Data::Enum(variants) => {
+                for variant in variants {
+                    check_tuple_fields(variant.fields.iter(), TUPLE_ELEMENTS.len());
+                }
+            }
+        }
+
+        atomic_ordering(self.ordering.as_ref());
+
+        if self.packed() {
+            self.validate_packed();
+        }
+
+        if self.reflect.is_present() {
+            self.validate_reflect_lifetimes();
+        }
+
+        if let Data::Enum(variants) = &self.data {
+            for flag in std::iter::once(self.has_field).chain(
+                variants
+                    .iter()
+                    .flat_map(|v| v.fields.iter())
+                    .flat_map(|f| [f.has_field, f.skip_has_field]),
+            ) {
+                if flag.is_present() {
+                    emit_error!(flag.span(), "`has_field` can only be used on structs");
+                }
+            }
+
+            for field in variants.iter().flat_map(|v| v.fields.iter()) {
+                if field.pin.is_present() {
+                    emit_error!(field.pin.span(), "`pin` can only be used on structs");
+                }
+
+                for bits in field.bits.iter() {
+                    emit_error!(bits.name.span(), "`bits` can only be used on structs");
+                }
+
+                for (name, flag) in field.byte_order.selected() {
+                    emit_error!(flag.span(), "`{}` can only be used on structs", name);
+                }
+            }
+        }
+
+        if self.layout.is_present() && self.data.is_enum() {
+            emit_error!(self.layout.span(), "`layout` can only be used on structs");
+        }
+
+        if self.kind.is_present() && !self.data.is_enum() {
+            emit_error!(self.kind.span(), "`kind` can only be used on enums");
+        }
+
+        if self.unwrap.is_present() && !self.data.is_enum() {
+            emit_error!(self.unwrap.span(), "`unwrap` can only be used on enums");
+        }
+
+        if let Some(enum_return) = self.enum_return.as_ref() {
+            if !self.data.is_enum() {
+                emit_error!(enum_return, "`enum_return` can only be used on enums");
+            } else {
+                self.result();
+            }
+        }
+
+        if let Some(field_enum) = self.field_enum.as_ref() {
+            if self.data.is_enum() {
+                emit_error!(field_enum, "`field_enum` can only be used on structs");
+            } else if field_enum.parse::<Ident>().is_err() {
+                emit_error!(field_enum, "`field_enum` must be a valid identifier");
+            }
+        }
+
+        if let Some(async_locks) = self.async_locks.as_ref() {
+            if !cfg!(feature = "async") {
+                emit_error!(
+                    async_locks.span(),
+                    "`async_locks` requires the `async` feature of getters2"
+                );
+            }
+        }
+
+        if let Some(variants) = self.data.as_ref().take_enum() {
+            for variant in variants {
+                validate_flags(|a| variant.flags(a));
+
+                if variant.fields.is_empty() {
+                    for access in Access::ALL {
+                        let (enable, skip) = variant.flags(access);
+                        for (name, flag) in access
+                            .enable()
+                            .map(|e| (e, enable))
+                            .into_iter()
+                            .chain(std::iter::once((access.skip(), skip)))
+                            .filter(|(_, f)| f.is_present())
+                        {
+                            emit_error!(
+                                flag.span(),
+                                "`{}` has no effect on a variant without fields",
+                                name
+                            );
+                        }
+                    }
+                    continue;
+                }
+
+                for access in Access::ALL {
+                    let (enable, skip) = variant.flags(access);
+                    let inherited = self.inherited(access, None);
+                    warnings.extend(self.validate_redundant(access, enable, skip, inherited));
+                }
+                warnings.extend(self.validate_reflect(variant.skip_reflect));
+
+                for field in variant.fields.iter() {
+                    warnings.extend(self.validate_field(Some(variant), field));
+                }
+            }
+        } else if let Some(fields) = self.data.as_ref().take_struct() {
+            for field in fields.iter() {
+                warnings.extend(self.validate_field(None, field));
+            }
+        }
+
+        if let Some(bound) = self.bound.as_ref() {
+            let fields = match &self.data {
+                Data::Struct(fields) => fields.iter().map(|f| (None, f)).collect::<Vec<_>>(),
+                Data::Enum(variants) => variants
+                    .iter()
+                    .flat_map(|v| v.fields.iter().map(move |f| (Some(v), f)))
+                    .collect(),
+            };
+
+            // NOTE: Fields with their own `bound` replace this one
+            if !fields
+                .iter()
+                .any(|(v, f)| f.bound.is_none() && self.uses_bound(*v, f))
+            {
+                warnings.extend(warning(
+                    bound.span(),
+                    "`bound` has no effect without a field with `clone` or `deref` which does \
+                     not set its own `bound`",
+                ));
+            }
+        }
+
+        abort_if_dirty();
+
+        self.validate_names();
+        abort_if_dirty();
+
+        warnings
+    }
+
+    /// Report every accessor requested on a `repr(packed)` struct which needs a reference to a
+    /// field, since references to its fields may be unaligned
+    fn validate_packed(&self) {
+        let container = [
+            ("mutable", self.mutable.span(), self.mutable.is_present()),
+            ("clone", self.clone.span(), self.clone.is_present()),
+            ("lens", self.lens.span(), self.lens.is_present()),
+            ("reborrow", self.reborrow.span(), self.reborrow.is_present()),
+            ("interior", self.interior.span(), self.interior.is_present()),
+            (
+                "has_field",
+                self.has_field.span(),
+                self.has_field.is_present(),
+            ),
+            ("reflect", self.reflect.span(), self.reflect.is_present()),
+            ("split", self.split.span(), self.split.is_present()),
+            (
+                "field_enum",
+                self.field_enum
+                    .as_ref()
+                    .map_or_else(Span::call_site, |f| f.span()),
+                self.field_enum.is_some(),
+            ),
+        ];
+        let fields = self
+            .data
+            .as_ref()
+            .take_struct()
+            .into_iter()
+            .flat_map(|fields| {
+                fields.into_iter().flat_map(|field| {
+                    Access::ALL
+                        .into_iter()
+                        .filter(|access| *access != Access::Deref)
+                        .filter_map(|access| {
+                            let (enable, _) = field.flags(access);
+                            access
+                                .enable()
+                                .map(|name| (name, enable.span(), enable.is_present()))
+                        })
+                        .chain([
+                            ("pin", field.pin.span(), field.pin.is_present()),
+                            ("flatten", field.flatten.span(), field.flatten.is_present()),
+                        ])
+                        .collect::<Vec<_>>()
+                })
+            });
+
+        for (name, span, _) in container
+            .into_iter()
+            .chain(fields)
+            .filter(|(_, _, present)| *present)
+        {
+            emit_error!(
+                span,
+                "`{}` cannot be used on a `repr(packed)` struct, whose fields may be unaligned",
+                name;
+                help = "`repr(packed)` structs only get by-value `_get`, `_set` and `_deref` \
+                        accessors, which do not need a reference to the field"
+            );
+        }
+    }
+
+    /// Report each name which is generated for more than one field. Names are compared in
+    /// full, so this catches collisions between variant prefixes, tuple field ordinals,
+    /// renamed fields and reflection methods alike.
+    fn validate_names(&self) {
+        // Every generated name, with the index of the source in `sources` it was generated for
+        let mut names = Vec::new();
+        let mut sources = Vec::new();
+
+        let mut field_names = |variant: Option<&GettersVariant>, fields: &Fields<GettersField>| {
+            for (i, field) in fields.iter().enumerate() {
+                let base = field.name(i, fields.len());
+                let base = match variant {
+                    Some(v) => format!("{}_{}", v.prefix(), base),
+                    None => base.to_string(),
+                };
+
+                for access in Access::ALL {
+                    if !self.generates(access, variant, field) {
+                        continue;
+                    }
+
+                    if access == Access::Ref && self.packed() {
+                        names.push((format!("{}_get", base), sources.len()));
+                        names.push((format!("{}_set", base), sources.len()));
+                    } else {
+                        for name in access.generated(&base, self.interior(field)) {
+                            names.push((name, sources.len()));
+                        }
+                    }
+                }
+
+                if variant.is_none() && !field.byte_order.selected().is_empty() {
+                    let name = field.name(i, fields.len());
+                    names.push((name.to_string(), sources.len()));
+                    names.push((format!("set_{}", name), sources.len()));
+                }
+
+                for bits in field.bits.iter().filter(|_| variant.is_none()) {
+                    let name = bits.name();
+                    names.push((name.to_string(), sources.len()));
+                    names.push((format!("set_{}", name), sources.len()));
+                }
+
+                if variant.is_some() && self.unwrap.is_present() {
+                    if self.generates(Access::Ref, variant, field) {
+                        names.push((format!("unwrap_{}", base), sources.len()));
+                    }
+                    if self.generates(Access::Mutable, variant, field) {
+                        names.push((format!("unwrap_{}_mut", base), sources.len()));
+                    }
+                }
+
+                if variant.is_none() && self.layout.is_present() && !field.skip_layout.is_present()
+                {
+                    let name = field.name(i, fields.len()).to_string().to_ascii_uppercase();
+                    names.push((format!("{}_OFFSET", name), sources.len()));
+                    names.push((format!("{}_SIZE", name), sources.len()));
+                }
+
+                if variant.is_none() && self.pinned() && !field.skip.is_present() {
+                    if field.pin.is_present() {
+                        names.push((format!("{}_pin", base), sources.len()));
+                    } else {
+                        names.push((format!("{}_pin_unpinned", base), sources.len()));
+                    }
+                }
+
+                sources.push((field.span(), field.describe(i, variant)));
+            }
+        };
+
+        match &self.data {
+            Data::Struct(fields) => field_names(None, fields),
+            Data::Enum(variants) => {
+                for variant in variants {
+                    field_names(Some(variant), &variant.fields)
+                }
+            }
+        }
+
+        if self.reflect.is_present() {
+            for name in ["field_names", "get_field", "get_field_mut"]
+                .into_iter()
+                .chain(self.data.is_enum().then_some("variant_name"))
+            {
+                names.push((name.to_string(), sources.len()));
+            }
+            sources.push((self.reflect.span(), "`reflect`".to_string()));
+        }
+
+        if self.split.is_present() {
+            match &self.data {
+                Data::Struct(_) => {
+                    names.push(("split".to_string(), sources.len()));
+                    names.push(("split_mut".to_string(), sources.len()));
+                }
+                Data::Enum(variants) => {
+                    for variant in variants.iter().filter(|v| !v.fields.is_empty()) {
+                        names.push((format!("split_{}", variant.prefix()), sources.len()));
+                        names.push((format!("split_{}_mut", variant.prefix()), sources.len()));
+                    }
+                }
+            }
+            sources.push((self.split.span(), "`split`".to_string()));
+        }
+
+        if self.kind.is_present() && self.data.is_enum() {
+            for name in ["kind", "variant_index", "VARIANT_NAMES"]
+                .into_iter()
+                .chain((!self.reflect.is_present()).then_some("variant_name"))
+            {
+                names.push((name.to_string(), sources.len()));
+            }
+            sources.push((self.kind.span(), "`kind`".to_string()));
+        }
+
+        if self.layout.is_present() && !self.data.is_enum() {
+            names.push(("FIELD_COUNT".to_string(), sources.len()));
+            sources.push((self.layout.span(), "`layout`".to_string()));
+        }
+
+        if let Some(field_enum) = self.field_enum.as_ref() {
+            let mutable = self.data.as_ref().take_struct().is_some_and(|fields| {
+                fields.iter().any(|f| {
+                    self.generates(Access::Ref, None, f) && self.generates(Access::Mutable, None, f)
+                })
+            });
+            names.push(("get".to_string(), sources.len()));
+            if mutable {
+                names.push(("get_mut".to_string(), sources.len()));
+            }
+            sources.push((field_enum.span(), "`field_enum`".to_string()));
+        }
+
+        let mut seen = std::collections::HashMap::new();
+        let mut reported = std::collections::HashSet::new();
+
+        for (name, source) in names {
+            let first = *seen.entry(name.clone()).or_insert(source);
+
+            if first != source && reported.insert((first, source)) {
+                let (first_span, first_description) = &sources[first];
+                let (span, description) = &sources[source];
+                emit_error!(
+                    span,
+                    "`{}` is generated for both {} and {}",
+                    name,
+                    first_description,
+                    description;
+                    note = *first_span => "{} also generates `{}`", first_description, name;
+                    help = "use `#[getters(rename = \"...\")]` to choose a different name"
+                );
+            }
+        }
+    }
+
+    /// Report contradicting flags on a field, and return warnings for flags which have no
+    /// effect on it
+    fn validate_field(
+        &self,
+        variant: Option<&GettersVariant>,
+        field: &GettersField,
+    ) -> TokenStream2 {
+        let mut warnings = TokenStream2::new();
+
+        validate_flags(|a| field.flags(a));
+
+        if let [(first, _), rest @ ..] = field.byte_order.selected().as_slice() {
+            for (name, flag) in rest {
+                emit_error!(
+                    flag.span(),
+                    "`{}` conflicts with `{}`, a field can only be read as one integer",
+                    name,
+                    first
+                );
+            }
+        }
+
+        for access in Access::ALL {
+            let (enable, skip) = field.flags(access);
+            let inherited = self.inherited(access, variant);
+            warnings.extend(self.validate_redundant(access, enable, skip, inherited));
+        }
+        warnings.extend(self.validate_reflect(field.skip_reflect));
+
+        if field.skip_layout.is_present() && !self.layout.is_present() {
+            warnings.extend(warning(
+                field.skip_layout.span(),
+                &format!(
+                    "`skip_layout` has no effect because {} does not enable `layout`",
+                    self.origin(Some(Level::Container))
+                ),
+            ));
+        }
+
+        if field.reborrow.is_present() && !matches!(field.ty, Type::Reference(_)) {
+            emit_error!(
+                field.reborrow.span(),
+                "`reborrow` requires a field whose type is a reference"
+            );
+        }
+
+        if field.interior.is_present() && self.interior(field).is_none() {
+            emit_error!(
+                field.interior.span(),
+                "`interior` requires a field whose type is a `Cell`, `RefCell`, `Mutex`, \
+                 `RwLock` or atomic";
+                help = "use `interior_type` to name the type a type alias or a type outside of \
+                        `std` behaves like"
+            );
+        }
+
+        if let Some(interior_type) = field.interior_type.as_ref() {
+            if !self.generates(Access::Interior, variant, field) {
+                warnings.extend(warning(
+                    interior_type.span(),
+                    "`interior_type` has no effect without `interior`",
+                ));
+            }
+        }
+
+        if let Some(ordering) = field.ordering.as_ref() {
+            self.ordering(field);
+
+            if !self.generates(Access::Interior, variant, field)
+                || !matches!(
+                    self.interior(field),
+                    Some(Interior {
+                        kind: InteriorKind::Atomic,
+                        ..
+                    })
+                )
+            {
+                warnings.extend(warning(
+                    ordering.span(),
+                    "`ordering` has no effect without `interior` on an atomic field",
+                ));
+            }
+        }
+
+        if let Some(bound) = field.bound.as_ref() {
+            if !self.uses_bound(variant, field) {
+                warnings.extend(warning(
+                    bound.span(),
+                    "`bound` has no effect without `clone` or `deref`",
+                ));
+            }
+        }
+
+        if let Some(prefix) = field.prefix.as_ref() {
+            if !field.flatten.is_present() {
+                warnings.extend(warning(
+                    prefix.span(),
+                    "`prefix` has no effect without `flatten`",
+                ));
+            }
+        }
+
+        warnings
+    }
+
+    /// Return a warning if an item enables a kind of accessor which is already enabled by
+    /// an enclosing item, or skips one which is not enabled to begin with
+    fn validate_redundant(
+        &self,
+        access: Access,
+        enable: Flag,
+        skip: Flag,
+        inherited: Option<Level>,
+    ) -> TokenStream2 {
+        match (access.enable(), inherited) {
+            (Some(name), Some(level)) if enable.is_present() => warning(
+                enable.span(),
+                &format!(
+                    "`{}` is already enabled by {}",
+                    name,
+                    self.origin(Some(level))
+                ),
+            ),
+            (Some(name), None) if skip.is_present() => warning(
+                skip.span(),
+                &format!(
+                    "`{}` has no effect because `{}` is not enabled here",
+                    access.skip(),
+                    name
+                ),
+            ),
+            (None, None) if skip.is_present() => warning(
+                skip.span(),
+                &format!(
+                    "`{}` has no effect because the variant already skips its fields",
+                    access.skip()
+                ),
+            ),
+            _ => TokenStream2::new(),
+        }
+    }
+
+    /// Report each lifetime parameter borrowed by a reflected field, since reflected fields
+    /// are returned as `dyn Any`, which must be `'static`
+    fn validate_reflect_lifetimes(&self) {
+        let types = self.reflected_types();
+
+        for param in self.generics.lifetimes() {
+            let lifetime = &param.lifetime;
+
+            if types
+                .iter()
+                .any(|ty| mentions_ident(ty.to_token_stream(), &lifetime.ident))
+            {
+                emit_error!(
+                    lifetime,
+                    "`reflect` cannot be used with fields borrowing for `{}`, because reflected \
+                     fields must be `'static`",
+                    lifetime;
+                    help = "use `skip_reflect` on the fields which borrow for `{}`", lifetime
+                );
+            }
+        }
+    }
+
+    /// The types of the fields which `reflect` returns as `dyn Any`
+    fn reflected_types(&self) -> Vec<&Type> {
+        match &self.data {
+            Data::Struct(fields) => fields
+                .iter()
+                .filter(|f| !f.skip_reflect.is_present())
+                .map(|f| &f.ty)
+                .collect(),
+            Data::Enum(variants) => variants
+                .iter()
+                .filter(|v| !v.skip_reflect.is_present())
+                .flat_map(|v| v.fields.iter())
+                .filter(|f| !f.skip_reflect.is_present())
+                .map(|f| &f.ty)
+                .collect(),
+        }
+    }
+
+    /// The header of the impl block of `reflect`, which requires the type parameters of the
+    /// reflected fields to be `'static`
+    fn reflect_header(&self) -> TokenStream2 {
+        let ident = &self.ident;
+        let types = self.reflected_types();
+        let mut generics = self.generics.clone();
+        let params = self
+            .generics
+            .type_params()
+            .map(|p| &p.ident)
+            .filter(|p| {
+                types
+                    .iter()
+                    .any(|ty| mentions_ident(ty.to_token_stream(), p))
+            })
+            .collect::<Vec<_>>();
+        generics.make_where_clause().predicates.extend(
+            params
+                .iter()
+                .map(|p| -> WherePredicate { parse_quote!(#p: 'static) }),
+        );
+        let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+        quote!(impl #impl_generics #ident #ty_generics #where_clause)
+    }
+
+    /// Return a warning if an item skips reflection when it is not enabled
+    fn validate_reflect(&self, skip: Flag) -> TokenStream2 {
+        if skip.is_present() && !self.reflect.is_present() {
+            warning(
+                skip.span(),
+                &format!(
+                    "`skip_reflect` has no effect because {} does not enable `reflect`",
+                    self.origin(Some(Level::Container))
+                ),
+            )
+        } else {
+            TokenStream2::new()
+        }
+    }
+
+    /// The accessors of each bitfield of an integer struct field. The field is read and
+    /// written by value, so bitfields of `repr(packed)` structs work too.
+    fn bits_field(&self, field: &GettersField, index: usize) -> TokenStream2 {
+        if field.bits.is_empty() {
+            return TokenStream2::new();
+        }
+
+        let ty = &field.ty;
+        let Some(width) = integer_width(ty) else {
+            abort!(ty, "`bits` requires a field of a fixed-width integer type");
+        };
+        let unsigned = format_ident!("u{}", width);
+        let unsigned = quote!(::core::primitive::#unsigned);
+        let member = if let Some(ident) = field.ident.as_ref() {
+            Member::Named(ident.clone())
+        } else {
+            Member::Unnamed(Index {
+                index: index as u32,
+                span: Span::call_site(),
+            })
+        };
+
+        field
+            .bits
+            .iter()
+            .map(|bits| {
+                let name = bits.name();
+                let setter = format_ident!("set_{}", name);
+                let (start, end) = bits.range(width);
+                let len = end - start;
+                let ret = [8, 16, 32, 64, 128]
+                    .into_iter()
+                    .find(|w| *w >= len)
+                    .map(|w| format_ident!("u{}", w))
+                    .map(|w| quote!(::core::primitive::#w));
+                let mask = LitInt::new(
+                    &format!("{:#x}u{}", u128::MAX >> (128 - len), width),
+                    Span::call_site(),
+                );
+                let get_doc = format!("Return bits {}..{} of the field", start, end);
+                let set_doc = format!("Set bits {}..{} of the field", start, end);
+                let overflow = format!("{{}} does not fit in the {} bits of `{}`", len, name);
+
+                quote! {
+                    #[inline(always)]
+                    #[doc = #get_doc]
+                    pub fn #name(&self) -> #ret {
+                        ((self.#member as #unsigned >> #start) & #mask) as #ret
+                    }
+
+                    #[inline(always)]
+                    #[doc = #set_doc]
+                    pub fn #setter(&mut self, value: #ret) {
+                        ::core::debug_assert!((value as #unsigned) <= #mask, #overflow, value);
+                        let mask: #unsigned = #mask << #start;
+                        let bits = ((value as #unsigned) << #start) & mask;
+                        self.#member = ((self.#member as #unsigned & !mask) | bits) as #ty;
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The accessors of a byte array struct field read and written as an integer in the byte
+    /// order selected by a flag like `be_u32`. The field is read and written by value, so byte
+    /// array fields of `repr(packed)` structs work too.
+    fn bytes_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
+        let Some((flag, _)) = field.byte_order.selected().first().copied() else {
+            return TokenStream2::new();
+        };
+
+        let (order, int) = flag.split_at(2);
+        let width = int[2..].parse::<u32>().unwrap_or_default() / 8;
+        let int = format_ident!("{}", &int[1..]);
+        let len = match &field.ty {
+            Type::Array(array) => Some(array),
+            _ => None,
+        }
+        .filter(|a| matches!(&*a.elem, Type::Path(p) if p.path.is_ident("u8")))
+        .and_then(|a| match &a.len {
+            Expr::Lit(ExprLit {
+                lit: Lit::Int(len), ..
+            }) => len.base10_parse::<u32>().ok(),
+            // NOTE: Lengths which are not literals are checked by the compiler instead
+            _ => Some(width),
+        });
+
+        if len != Some(width) {
+            abort!(
+                field.ty,
+                "`{}` requires a field of type `[u8; {}]`",
+                flag,
+                width
+            );
+        }
+
+        let name = field.name(index, max);
+        let setter = format_ident!("set_{}", name);
+        let (from_bytes, to_bytes) = (
+            format_ident!("from_{}_bytes", order),
+            format_ident!("to_{}_bytes", order),
+        );
+        let order = if order == "be" { "big" } else { "little" };
+        let get_doc = format!("Return the field read as a {} endian `{}`", order, int);
+        let set_doc = format!("Set the field to a {} endian `{}`", order, int);
+        let member = if let Some(ident) = field.ident.as_ref() {
+            Member::Named(ident.clone())
+        } else {
+            Member::Unnamed(Index {
+                index: index as u32,
+                span: Span::call_site(),
+            })
+        };
+
+        quote! {
+            #[inline(always)]
+            #[doc = #get_doc]
+            pub fn #name(&self) -> ::core::primitive::#int {
+                ::core::primitive::#int::#from_bytes(self.#member)
+            }
+
+            #[inline(always)]
+            #[doc = #set_doc]
+            pub fn #setter(&mut self, value: ::core::primitive::#int) {
+                self.#member = ::core::primitive::#int::#to_bytes(value);
+            }
+        }
+    }
+
+    /// The by-value accessors of a field of a `repr(packed)` struct, which read and write the
+    /// field without creating a reference to it
+    fn packed_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
+        let ty = &field.ty;
+        let name = field.name(index, max);
+        let get = format_ident!("{}_get", name);
+        let set = format_ident!("{}_set", name);
+        let deref = format_ident!("{}_deref", name);
+        let bound = self.bound(field, quote!(::core::marker::Copy));
+        let member = if let Some(ident) = field.ident.as_ref() {
+            Member::Named(ident.clone())
+        } else {
+            Member::Unnamed(Index {
+                index: index as u32,
+                span: Span::call_site(),
+            })
+        };
+        // NOTE: Reading a field by value copies it without creating a reference to it
+        let value = quote!(&{ self.#member });
+        let maybe_deref = self.access(Access::Deref, None, field).map(|level| {
+            let copy = deref_field(ty, value.clone(), &deref, self.origin(Some(level)));
+
+            quote! {
+                #[inline(always)]
+                /// Return a copy of the field, which may be unaligned
+                pub fn #deref(&self) -> #ty #bound {
+                    #copy
+                }
+            }
+        });
+
+        if !self.generates(Access::Ref, None, field) {
+            return maybe_deref.unwrap_or_default();
+        }
+
+        let copy = copy_field(
+            ty,
+            value,
+            &get,
+            "the accessor is generated because the struct is `repr(packed)`",
+            "add `#[getters(skip)]` to the field to skip the accessor",
+        );
+
+        quote! {
+            #maybe_deref
+
+            #[inline(always)]
+            /// Return a copy of the field, which may be unaligned
+            pub fn #get(&self) -> #ty #bound {
+                #copy
+            }
+
+            #[inline(always)]
+            /// Set the field, which may be unaligned
+            pub fn #set(&mut self, value: #ty) #bound {
+                self.#member = value;
+            }
+        }
+    }
+
+    fn method_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
+        if self.packed() {
+            return self.packed_field(field, index, max);
+        }
+
+        let ty = &field.ty;
+        let immutable = self.access(Access::Ref, None, field).is_some();
+        let mutable = self.access(Access::Mutable, None, field).is_some();
+        let clone = self.access(Access::Clone, None, field);
+        let deref = self.access(Access::Deref, None, field);
+        let clone_origin = self.origin(clone);
+        let deref_origin = self.origin(deref);
+        let clone = clone.is_some();
+        let deref = deref.is_some();
+        let clone_bound = self.bound(field, quote!(::core::clone::Clone));
+        let deref_bound = self.bound(field, quote!(::core::marker::Copy));
+        let name = field.name(index, max);
+
+        let (immutable, maybe_mutable, maybe_clone, maybe_deref) =
+            if let Some(ident) = field.ident.as_ref() {
+                let ident_ref = format_ident!("{}_ref", name);
+                let ident_mut = format_ident!("{}_mut", name);
+                let ident_clone = format_ident!("{}_clone", name);
+                let ident_deref = format_ident!("{}_deref", name);
+                let clone_body = clone_field(ty, quote!(&self.#ident), &ident_clone, clone_origin);
+                let deref_body = deref_field(ty, quote!(&self.#ident), &ident_deref, deref_origin);
+                (
+                    immutable
+                        .then_some(quote! {
+                            #[inline(always)]
+                            /// Return an immutable reference to the field
+                            pub fn #ident_ref(&self) -> &#ty {
+                                &self.#ident
+                            }
+                        })
+                        .unwrap_or_default(),
+                    mutable
+                        .then_some(quote! {
+                            #[inline(always)]
+                            /// Return a mutable reference to the field
                             pub fn #ident_mut(&mut self) -> &mut #ty {
                                 &mut self.#ident
                             }
@@ -399,8 +2462,59 @@ impl GettersInput {
                         .then_some(quote! {
                             #[inline(always)]
                             /// Return a clone of the field
-                            pub fn #ident_clone(&self) -> #ty {
-                                self.#ident.clone()
+                            pub fn #ident_clone(&self) -> #ty #clone_bound {
+                                #clone_body
+                            }
+                        })
+                        .unwrap_or_default(),
+                    deref
+                        .then_some(quote! {
+                            #[inline(always)]
+                            /// Return a copy of the field
+                            pub fn #ident_deref(&self) -> #ty #deref_bound {
+                                #deref_body
+                            }
+                        })
+                        .unwrap_or_default(),
+                )
+            } else {
+                // Field with no ident, we generate a named method
+                let name_ref = format_ident!("{}_ref", name);
+                let name_mut = format_ident!("{}_mut", name);
+                let name_clone = format_ident!("{}_clone", name);
+                let name_deref = format_ident!("{}_deref", name);
+                let index = Member::Unnamed(Index {
+                    index: index as u32,
+                    span: Span::call_site(),
+                });
+                let clone_body = clone_field(ty, quote!(&self.#index), &name_clone, clone_origin);
+                let deref_body = deref_field(ty, quote!(&self.#index), &name_deref, deref_origin);
+
+                (
+                    immutable
+                        .then_some(quote! {
+                            #[inline(always)]
+                            /// Return an immutable reference to the field
+                            pub fn #name_ref(&self) -> &#ty {
+                                &self.#index
+                            }
+                        })
+                        .unwrap_or_default(),
+                    mutable
+                        .then_some(quote! {
+                            #[inline(always)]
+                            /// Return a mutable reference to the field
+                            pub fn #name_mut(&mut self) -> &mut #ty {
+                                &mut self.#index
+                            }
+                        })
+                        .unwrap_or_default(),
+                    clone
+                        .then_some(quote! {
+                            #[inline(always)]
+                            /// Return a clone of the field
+                            pub fn #name_clone(&self) -> #ty #clone_bound {
+                                #clone_body
                             }
                         })
                         .unwrap_or_default(),
@@ -408,273 +2522,1524 @@ impl GettersInput {
                         .then_some(quote! {
                             #[inline(always)]
                             /// Return a copy of the field
-                            pub fn #ident_deref(&self) -> #ty {
-                                self.#ident
+                            pub fn #name_deref(&self) -> #ty #deref_bound {
+                                #deref_body
                             }
                         })
                         .unwrap_or_default(),
                 )
+            };
+
+        quote! {
+            #immutable
+            #maybe_mutable
+            #maybe_clone
+            #maybe_deref
+        }
+    }
+
+    fn method_variant(
+        &self,
+        field: &GettersField,
+        index: usize,
+        max: usize,
+        variant: &GettersVariant,
+    ) -> TokenStream2 {
+        let ty = &field.ty;
+        let immutable = self.access(Access::Ref, Some(variant), field).is_some();
+        let mutable = self.access(Access::Mutable, Some(variant), field).is_some();
+        let clone = self.access(Access::Clone, Some(variant), field);
+        let deref = self.access(Access::Deref, Some(variant), field);
+        let clone_origin = self.origin(clone);
+        let deref_origin = self.origin(deref);
+        let clone = clone.is_some();
+        let deref = deref.is_some();
+        let clone_bound = self.bound(field, quote!(::core::clone::Clone));
+        let deref_bound = self.bound(field, quote!(::core::marker::Copy));
+        let prefix = variant.prefix();
+        let name = field.name(index, max);
+        let ident_ref = format_ident!("{}_{}_ref", prefix, name);
+        let ident_mut = format_ident!("{}_{}_mut", prefix, name);
+        let ident_clone = format_ident!("{}_{}_clone", prefix, name);
+        let ident_deref = format_ident!("{}_{}_deref", prefix, name);
+        let unwrap_ref = format_ident!("unwrap_{}_{}", prefix, name);
+        let unwrap_mut = format_ident!("unwrap_{}_{}_mut", prefix, name);
+
+        let (pattern, element) = if let Some(ident) = field.ident.as_ref() {
+            (quote!({ #ident, .. }), ident.clone())
+        } else {
+            // Field with no ident, we bind it to a named element of the tuple
+            let elements = tuple_elements(index, max);
+            (quote!((#elements)), tuple_element_name(index))
+        };
+        let clone_body = clone_field(ty, quote!(#element), &ident_clone, clone_origin);
+        let deref_body = deref_field(ty, quote!(#element), &ident_deref, deref_origin);
+
+        let maybe_immutable = immutable.then(|| {
+            let (ret, body) =
+                self.variant_return(variant, &pattern, quote!(&#ty), quote!(#element));
+            quote! {
+                #[inline(always)]
+                /// Return an immutable reference to the field
+                pub fn #ident_ref(&self) -> #ret {
+                    #body
+                }
+            }
+        });
+        let maybe_mutable = mutable.then(|| {
+            let (ret, body) =
+                self.variant_return(variant, &pattern, quote!(&mut #ty), quote!(#element));
+            quote! {
+                #[inline(always)]
+                /// Return a mutable reference to the field
+                pub fn #ident_mut(&mut self) -> #ret {
+                    #body
+                }
+            }
+        });
+        let maybe_clone = clone.then(|| {
+            let (ret, body) = self.variant_return(variant, &pattern, quote!(#ty), clone_body);
+            quote! {
+                #[inline(always)]
+                /// Return a clone of the field
+                pub fn #ident_clone(&self) -> #ret #clone_bound {
+                    #body
+                }
+            }
+        });
+        let maybe_deref = deref.then(|| {
+            let (ret, body) = self.variant_return(variant, &pattern, quote!(#ty), deref_body);
+            quote! {
+                #[inline(always)]
+                /// Return a copy of the field
+                pub fn #ident_deref(&self) -> #ret #deref_bound {
+                    #body
+                }
+            }
+        });
+        let maybe_unwrap = self.unwrap.is_present().then(|| {
+            let unwrap_immutable = immutable.then(|| {
+                let body = self.variant_unwrap(variant, &pattern, &unwrap_ref, quote!(#element));
+                quote! {
+                    #[inline(always)]
+                    #[track_caller]
+                    /// Return an immutable reference to the field, panicking if the variant is
+                    /// not active
+                    pub fn #unwrap_ref(&self) -> &#ty {
+                        #body
+                    }
+                }
+            });
+            let unwrap_mutable = mutable.then(|| {
+                let body = self.variant_unwrap(variant, &pattern, &unwrap_mut, quote!(#element));
+                quote! {
+                    #[inline(always)]
+                    #[track_caller]
+                    /// Return a mutable reference to the field, panicking if the variant is not
+                    /// active
+                    pub fn #unwrap_mut(&mut self) -> &mut #ty {
+                        #body
+                    }
+                }
+            });
+
+            quote! {
+                #unwrap_immutable
+                #unwrap_mutable
+            }
+        });
+
+        quote! {
+            #maybe_immutable
+            #maybe_mutable
+            #maybe_clone
+            #maybe_deref
+            #maybe_unwrap
+        }
+    }
+
+    /// The return type and body of an enum accessor returning `value` of type `ty` if the
+    /// enum matches `pattern`. This is an `Option`, or with `enum_return = "result"` a
+    /// `Result` whose error records the expected and active variants.
+    fn variant_return(
+        &self,
+        variant: &GettersVariant,
+        pattern: &TokenStream2,
+        ty: TokenStream2,
+        value: TokenStream2,
+    ) -> (TokenStream2, TokenStream2) {
+        let variant_ident = &variant.ident;
+
+        if self.result() {
+            let expected = variant_ident.to_string();
+            let actual = self.active_variant_name();
+            (
+                quote!(::core::result::Result<#ty, ::getters2_core::VariantMismatch>),
+                quote! {
+                    if let Self::#variant_ident #pattern = self {
+                        ::core::result::Result::Ok(#value)
+                    } else {
+                        ::core::result::Result::Err(
+                            ::getters2_core::VariantMismatch::new(#expected, #actual),
+                        )
+                    }
+                },
+            )
+        } else {
+            (
+                quote!(::core::option::Option<#ty>),
+                quote! {
+                    if let Self::#variant_ident #pattern = self {
+                        ::core::option::Option::Some(#value)
+                    } else {
+                        ::core::option::Option::None
+                    }
+                },
+            )
+        }
+    }
+
+    /// The body of an `unwrap` accessor named `name`, returning `value` if the enum matches
+    /// `pattern` and panicking with the expected and active variants otherwise
+    fn variant_unwrap(
+        &self,
+        variant: &GettersVariant,
+        pattern: &TokenStream2,
+        name: &Ident,
+        value: TokenStream2,
+    ) -> TokenStream2 {
+        let variant_ident = &variant.ident;
+        let actual = self.active_variant_name();
+        let message = format!(
+            "called `{}::{}` on a `{{}}` value, expected `{}`",
+            self.ident, name, variant_ident
+        );
+
+        quote! {
+            if let Self::#variant_ident #pattern = self {
+                #value
             } else {
-                // Field with no ident, we generate a named method
-                let name = method_name(index, max);
-                let name_ref = format_ident!("{}_ref", name);
-                let name_mut = format_ident!("{}_mut", name);
-                let name_clone = format_ident!("{}_clone", name);
-                let name_deref = format_ident!("{}_deref", name);
-                let index = Member::Unnamed(Index {
+                ::core::panic!(#message, #actual)
+            }
+        }
+    }
+
+    /// The lens constant for a struct field, implementing `getters2_core::Lens`
+    fn lens_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
+        if self.access(Access::Lens, None, field).is_none() {
+            return TokenStream2::new();
+        }
+
+        let ty = &field.ty;
+        let (name, member) = if let Some(ident) = field.ident.as_ref() {
+            (ident.clone(), Member::Named(ident.clone()))
+        } else {
+            (
+                method_name(index, max),
+                Member::Unnamed(Index {
                     index: index as u32,
                     span: Span::call_site(),
-                });
+                }),
+            )
+        };
+        let constant = format_ident!(
+            "{}",
+            field.name(index, max).to_string().to_ascii_uppercase(),
+            span = field.span()
+        );
+        let name = name.to_string();
+
+        quote! {
+            /// A lens referring to the field
+            pub const #constant: ::getters2_core::FieldLens<Self, #ty> =
+                ::getters2_core::FieldLens::new(#name, |s| &s.#member, |s| &mut s.#member);
+        }
+    }
+
+    /// The prism constant for an enum variant field, implementing `getters2_core::Prism`
+    fn lens_variant(
+        &self,
+        field: &GettersField,
+        index: usize,
+        max: usize,
+        variant: &GettersVariant,
+    ) -> TokenStream2 {
+        if self.access(Access::Lens, Some(variant), field).is_none() {
+            return TokenStream2::new();
+        }
+
+        let ty = &field.ty;
+        let variant_ident = &variant.ident;
+
+        let (name, pattern, element) = if let Some(ident) = field.ident.as_ref() {
+            (ident.clone(), quote!({ #ident, .. }), ident.clone())
+        } else {
+            let elements = tuple_elements(index, max);
+            (
+                method_name(index, max),
+                quote!((#elements)),
+                tuple_element_name(index),
+            )
+        };
+        let constant = format_ident!(
+            "{}_{}",
+            variant.prefix().to_ascii_uppercase(),
+            field.name(index, max).to_string().to_ascii_uppercase(),
+            span = field.span()
+        );
+        let name = format!("{}::{}", variant_ident, name);
+
+        quote! {
+            /// A prism referring to the variant field
+            pub const #constant: ::getters2_core::VariantPrism<Self, #ty> =
+                ::getters2_core::VariantPrism::new(
+                    #name,
+                    |s| {
+                        if let Self::#variant_ident #pattern = s {
+                            ::core::option::Option::Some(#element)
+                        } else {
+                            ::core::option::Option::None
+                        }
+                    },
+                    |s| {
+                        if let Self::#variant_ident #pattern = s {
+                            ::core::option::Option::Some(#element)
+                        } else {
+                            ::core::option::Option::None
+                        }
+                    },
+                );
+        }
+    }
+
+    /// The accessor of a reference-typed struct field which returns the reference itself,
+    /// with its original lifetime for shared references or reborrowed from `&mut self` for
+    /// mutable references
+    fn reborrow_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
+        let Type::Reference(reference) = &field.ty else {
+            return TokenStream2::new();
+        };
+
+        if !self.generates(Access::Reborrow, None, field) {
+            return TokenStream2::new();
+        }
+
+        let name = field.name(index, max);
+        let member = if let Some(ident) = field.ident.as_ref() {
+            Member::Named(ident.clone())
+        } else {
+            Member::Unnamed(Index {
+                index: index as u32,
+                span: Span::call_site(),
+            })
+        };
+        let ty = &field.ty;
+        let elem = &reference.elem;
+
+        if reference.mutability.is_some() {
+            quote! {
+                #[inline(always)]
+                /// Return a mutable reborrow of the reference in the field
+                pub fn #name(&mut self) -> &mut #elem {
+                    &mut *self.#member
+                }
+            }
+        } else {
+            quote! {
+                #[inline(always)]
+                /// Return the reference in the field, with its original lifetime
+                pub fn #name(&self) -> #ty {
+                    self.#member
+                }
+            }
+        }
+    }
+
+    /// The accessor of a reference-typed enum variant field which returns the reference
+    /// itself, like `reborrow_field`, if the enum is the variant
+    fn reborrow_variant(
+        &self,
+        field: &GettersField,
+        index: usize,
+        max: usize,
+        variant: &GettersVariant,
+    ) -> TokenStream2 {
+        let Type::Reference(reference) = &field.ty else {
+            return TokenStream2::new();
+        };
+
+        if !self.generates(Access::Reborrow, Some(variant), field) {
+            return TokenStream2::new();
+        }
+
+        let name = format_ident!("{}_{}", variant.prefix(), field.name(index, max));
+        let (pattern, element) = if let Some(ident) = field.ident.as_ref() {
+            (quote!({ #ident, .. }), ident.clone())
+        } else {
+            let elements = tuple_elements(index, max);
+            (quote!((#elements)), tuple_element_name(index))
+        };
+        let ty = &field.ty;
+        let elem = &reference.elem;
+
+        if reference.mutability.is_some() {
+            let (ret, body) = self.variant_return(
+                variant,
+                &pattern,
+                quote!(&mut #elem),
+                quote!(&mut **#element),
+            );
+            quote! {
+                #[inline(always)]
+                /// Return a mutable reborrow of the reference in the field
+                pub fn #name(&mut self) -> #ret {
+                    #body
+                }
+            }
+        } else {
+            let (ret, body) =
+                self.variant_return(variant, &pattern, quote!(#ty), quote!(*#element));
+            quote! {
+                #[inline(always)]
+                /// Return the reference in the field, with its original lifetime
+                pub fn #name(&self) -> #ret {
+                    #body
+                }
+            }
+        }
+    }
+
+    /// The interior mutability accessors of a struct field
+    fn interior_field(&self, field: &GettersField, index: usize, max: usize) -> TokenStream2 {
+        let Some(interior) = self.interior(field) else {
+            return TokenStream2::new();
+        };
+
+        if !self.generates(Access::Interior, None, field) {
+            return TokenStream2::new();
+        }
+
+        let name = field.name(index, max);
+        let member = if let Some(ident) = field.ident.as_ref() {
+            Member::Named(ident.clone())
+        } else {
+            Member::Unnamed(Index {
+                index: index as u32,
+                span: Span::call_site(),
+            })
+        };
+
+        interior
+            .names(&name)
+            .into_iter()
+            .zip(interior.methods(&field.ty, self.ordering(field)))
+            .map(|(name, (args, ret, doc, body))| {
+                let ret = ret.map(|ret| quote!(-> #ret));
+                let asyncness = interior.kind.is_async().then(|| quote!(async));
+
+                quote! {
+                    #[inline(always)]
+                    #[doc = #doc]
+                    pub #asyncness fn #name(&self #args) #ret {
+                        let field = &self.#member;
+                        #body
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The interior mutability accessors of an enum variant field, like `interior_field`,
+    /// which return `None` if the enum is not the variant
+    fn interior_variant(
+        &self,
+        field: &GettersField,
+        index: usize,
+        max: usize,
+        variant: &GettersVariant,
+    ) -> TokenStream2 {
+        let Some(interior) = self.interior(field) else {
+            return TokenStream2::new();
+        };
+
+        if !self.generates(Access::Interior, Some(variant), field) {
+            return TokenStream2::new();
+        }
+
+        let name = format_ident!("{}_{}", variant.prefix(), field.name(index, max));
+        let (pattern, element) = if let Some(ident) = field.ident.as_ref() {
+            (quote!({ #ident, .. }), ident.clone())
+        } else {
+            let elements = tuple_elements(index, max);
+            (quote!((#elements)), tuple_element_name(index))
+        };
+
+        interior
+            .names(&name)
+            .into_iter()
+            .zip(interior.methods(&field.ty, self.ordering(field)))
+            .map(|(name, (args, ret, doc, body))| {
+                let ret = ret.unwrap_or_else(|| quote!(()));
+                let asyncness = interior.kind.is_async().then(|| quote!(async));
+                let (ret, body) = self.variant_return(
+                    variant,
+                    &pattern,
+                    ret,
+                    quote!({
+                        let field = #element;
+                        #body
+                    }),
+                );
+
+                quote! {
+                    #[inline(always)]
+                    #[doc = #doc]
+                    pub #asyncness fn #name(&self #args) -> #ret {
+                        #body
+                    }
+                }
+            })
+            .collect()
+    }
+
+    fn methods_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(i, f)| {
+                let methods = self.method_field(f, i, fields.len());
+                let bits = self.bits_field(f, i);
+                let bytes = self.bytes_field(f, i, fields.len());
+                let lens = self.lens_field(f, i, fields.len());
+                let reborrow = self.reborrow_field(f, i, fields.len());
+                let interior = self.interior_field(f, i, fields.len());
+
+                quote! {
+                    #methods
+                    #bits
+                    #bytes
+                    #lens
+                    #reborrow
+                    #interior
+                }
+            })
+            .collect::<TokenStream2>()
+    }
+
+    /// The member, prefix and helper macro path of every flattened field
+    fn flattened(&self, fields: &Fields<&GettersField>) -> Vec<(Member, LitStr, Path)> {
+        fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| f.flatten.is_present())
+            .map(|(i, f)| {
+                let member = if let Some(ident) = f.ident.as_ref() {
+                    Member::Named(ident.clone())
+                } else {
+                    Member::Unnamed(Index {
+                        index: i as u32,
+                        span: Span::call_site(),
+                    })
+                };
+                let prefix = LitStr::new(
+                    &f.prefix
+                        .as_ref()
+                        .map(LitStr::value)
+                        .unwrap_or_else(|| f.name(i, fields.len()).to_string()),
+                    f.span(),
+                );
+
+                (member, prefix, flatten_helper_path(&f.ty))
+            })
+            .collect()
+    }
+
+    /// Invocations of the helper macros of every flattened field. Each invocation expands to
+    /// accessors implemented by `header` which delegate to the accessors of the flattened
+    /// field, with their names prefixed by the field's prefix.
+    fn flatten_struct(
+        &self,
+        fields: &Fields<&GettersField>,
+        header: &TokenStream2,
+    ) -> TokenStream2 {
+        self.flattened(fields)
+            .into_iter()
+            .map(|(member, prefix, helper)| {
+                quote! {
+                    #helper! { { #header } { . #member } { #prefix } }
+                }
+            })
+            .collect::<TokenStream2>()
+    }
+
+    /// The helper macro exporting the accessors of this type, so that types flattening a field
+    /// of this type can generate delegating accessors for it. The accessors this type itself
+    /// delegates to flattened fields are collected from their helper macros by
+    /// `__flatten_helper`, so a helper never refers to the helpers of other types.
+    fn flatten_helper(&self, methods: &TokenStream2) -> TokenStream2 {
+        let ident = &self.ident;
+
+        if !self.generics.params.is_empty() {
+            let message = format!("Getters cannot flatten a field of generic type `{}`", ident);
+            let body = quote!(::core::compile_error!(#message););
+
+            return flatten_helper_macro(ident, &body, &body);
+        }
+
+        let signatures = syn::parse2::<ItemImpl>(quote!(impl _ { #methods }))
+            .map(|i| {
+                i.items
+                    .into_iter()
+                    .filter_map(|i| match i {
+                        ImplItem::Fn(f)
+                            if f.sig.generics.params.is_empty()
+                                && f.sig.generics.where_clause.is_none()
+                                && matches!(
+                                    f.sig.receiver(),
+                                    Some(r) if r.reference.is_some()
+                                ) =>
+                        {
+                            Some(f.sig)
+                        }
+                        _ => None,
+                    })
+                    .map(|s| quote!(#s;))
+                    .collect::<TokenStream2>()
+            })
+            .unwrap_or_default();
+        let pending = self
+            .data
+            .as_ref()
+            .take_struct()
+            .map(|fields| {
+                self.flattened(&fields)
+                    .into_iter()
+                    .map(|(_, prefix, helper)| quote!({ #helper } { #prefix }))
+                    .collect::<TokenStream2>()
+            })
+            .unwrap_or_default();
+
+        quote! {
+            ::getters2::__flatten_helper! { { #ident } { #signatures } { #pending } }
+        }
+    }
+
+    fn methods_enum(&self, variants: &[&GettersVariant]) -> TokenStream2 {
+        variants
+            .iter()
+            .map(|v| {
+                let variant_ident = &v.ident;
+
+                if let Some(discriminant) = v.discriminant.as_ref() {
+                    abort!(
+                        discriminant,
+                        "Getters cannot be derived for enums with discriminants"
+                    )
+                }
+
+                if let Some(field) = v.fields.iter().find(|f| f.flatten.is_present()) {
+                    abort!(
+                        field.ty,
+                        "Getters can only flatten fields of structs, not enum variants"
+                    )
+                }
+
+                v.fields
+                    .iter()
+                    .enumerate()
+                    .map(|(i, f)| {
+                        let methods = self.method_variant(f, i, v.fields.len(), v);
+                        let lens = self.lens_variant(f, i, v.fields.len(), v);
+                        let reborrow = self.reborrow_variant(f, i, v.fields.len(), v);
+                        let interior = self.interior_variant(f, i, v.fields.len(), v);
+
+                        quote! {
+                            #methods
+                            #lens
+                            #reborrow
+                            #interior
+                        }
+                    })
+                    .collect::<TokenStream2>()
+            })
+            .collect::<TokenStream2>()
+    }
 
-                (
-                    immutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return an immutable reference to the field
-                            pub fn #name_ref(&self) -> &#ty {
-                                &self.#index
-                            }
-                        })
-                        .unwrap_or_default(),
-                    mutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a mutable reference to the field
-                            pub fn #name_mut(&mut self) -> &mut #ty {
-                                &mut self.#index
-                            }
-                        })
-                        .unwrap_or_default(),
-                    clone
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a clone of the field
-                            pub fn #name_clone(&self) -> #ty {
-                                self.#index.clone()
-                            }
-                        })
-                        .unwrap_or_default(),
-                    deref
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a copy of the field
-                            pub fn #name_deref(&self) -> #ty {
-                                self.#index
-                            }
-                        })
-                        .unwrap_or_default(),
-                )
-            };
+    /// Runtime reflection over the fields of a struct, addressing each field by name
+    fn reflect_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        let (names, members): (Vec<_>, Vec<_>) = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.skip_reflect.is_present())
+            .map(|(i, f)| {
+                let name = f.name(i, fields.len()).to_string();
+
+                if let Some(ident) = f.ident.as_ref() {
+                    (name, Member::Named(ident.clone()))
+                } else {
+                    (
+                        name,
+                        Member::Unnamed(Index {
+                            index: i as u32,
+                            span: Span::call_site(),
+                        }),
+                    )
+                }
+            })
+            .unzip();
 
         quote! {
-            #immutable
-            #maybe_mutable
-            #maybe_clone
-            #maybe_deref
+            /// Return the names of the fields which can be accessed by name
+            pub fn field_names() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            /// Return an immutable reference to the field with the given name
+            pub fn get_field(
+                &self,
+                name: &str,
+            ) -> ::core::option::Option<&dyn ::core::any::Any> {
+                match name {
+                    #(#names => ::core::option::Option::Some(
+                        &self.#members as &dyn ::core::any::Any
+                    ),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// Return a mutable reference to the field with the given name
+            pub fn get_field_mut(
+                &mut self,
+                name: &str,
+            ) -> ::core::option::Option<&mut dyn ::core::any::Any> {
+                match name {
+                    #(#names => ::core::option::Option::Some(
+                        &mut self.#members as &mut dyn ::core::any::Any
+                    ),)*
+                    _ => ::core::option::Option::None,
+                }
+            }
         }
     }
 
-    #[allow(clippy::too_many_arguments)]
-    fn method_variant(
+    /// The companion enum of the fields of a struct named by `field_enum`, along with enums of
+    /// references to each field and the `get` and `get_mut` methods returning them. Fields
+    /// without an immutable getter are left out.
+    fn field_enum(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        let Some(field_enum) = self.field_enum.as_ref() else {
+            return TokenStream2::new();
+        };
+
+        let ident = &self.ident;
+        let vis = &self.vis;
+        let field_enum = field_enum
+            .parse::<Ident>()
+            .unwrap_or_else(|_| abort!(field_enum, "`field_enum` must be a valid identifier"));
+        let field_ref = format_ident!("{}Ref", field_enum);
+        let field_mut = format_ident!("{}Mut", field_enum);
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, parse_quote!('getters2));
+        let (ref_impl_generics, ref_ty_generics, _) = generics.split_for_impl();
+
+        let (names, (variants, (types, members))): (Vec<_>, (Vec<_>, (Vec<_>, Vec<_>))) = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| self.generates(Access::Ref, None, f))
+            .map(|(i, f)| {
+                let name = f.name(i, fields.len());
+                let variant = format_ident!(
+                    "{}",
+                    upper_camel_case(&name.to_string()),
+                    span = name.span()
+                );
+                let member = if let Some(ident) = f.ident.as_ref() {
+                    Member::Named(ident.clone())
+                } else {
+                    Member::Unnamed(Index {
+                        index: i as u32,
+                        span: Span::call_site(),
+                    })
+                };
+
+                (name.to_string(), (variant, (&f.ty, member)))
+            })
+            .unzip();
+        // The indices of the fields above which also have a mutable getter
+        let mutable = fields
+            .iter()
+            .filter(|f| self.generates(Access::Ref, None, f))
+            .enumerate()
+            .filter(|(_, f)| self.generates(Access::Mutable, None, f))
+            .map(|(i, _)| i)
+            .collect::<Vec<_>>();
+        let count = names.len();
+        let doc = format!("The fields of [`{}`]", ident);
+        let ref_doc = format!("An immutable reference to a field of [`{}`]", ident);
+        let mut_doc = format!(
+            "A mutable reference to a field of [`{}`] which has a mutable getter",
+            ident
+        );
+        // NOTE: Generic parameters which none of the referenced fields use, and the `'getters2`
+        // lifetime when there are no fields at all, are held by an uninhabited variant, which
+        // matches do not need to cover
+        let marker = |types: &[&Type]| {
+            let fields = types
+                .iter()
+                .map(|ty| (format_ident!("field"), *ty))
+                .collect::<Vec<_>>();
+            (fields.is_empty() || self.view_marker(&fields)).then(|| {
+                quote! {
+                    #[doc(hidden)]
+                    __Getters2Marker(
+                        ::core::convert::Infallible,
+                        ::core::marker::PhantomData<&'getters2 #ident #ty_generics>,
+                    ),
+                }
+            })
+        };
+        let ref_marker = marker(&types);
+        let field_mut = (!mutable.is_empty()).then(|| {
+            let mut_names = mutable.iter().map(|i| &names[*i]).collect::<Vec<_>>();
+            let mut_variants = mutable.iter().map(|i| &variants[*i]).collect::<Vec<_>>();
+            let mut_types = mutable.iter().map(|i| types[*i]).collect::<Vec<_>>();
+            let mut_members = mutable.iter().map(|i| &members[*i]).collect::<Vec<_>>();
+            let mut_marker = marker(&mut_types);
+            // NOTE: Fields without a mutable getter cannot be borrowed mutably
+            let rest =
+                (mut_names.len() < names.len()).then(|| quote!(_ => ::core::option::Option::None,));
+
+            quote! {
+                #[doc = #mut_doc]
+                #vis enum #field_mut #ref_impl_generics #where_clause {
+                    #(
+                        #[doc = ::core::concat!("The `", #mut_names, "` field")]
+                        #mut_variants(&'getters2 mut #mut_types),
+                    )*
+                    #mut_marker
+                }
+
+                impl #impl_generics #ident #ty_generics #where_clause {
+                    /// Return a mutable reference to a field chosen at runtime, through which it
+                    /// can be set, if the field has a mutable getter
+                    pub fn get_mut<'getters2>(
+                        &'getters2 mut self,
+                        field: #field_enum,
+                    ) -> ::core::option::Option<#field_mut #ref_ty_generics> {
+                        match field {
+                            #(
+                                #field_enum::#mut_variants => ::core::option::Option::Some(
+                                    #field_mut::#mut_variants(&mut self.#mut_members),
+                                ),
+                            )*
+                            #rest
+                        }
+                    }
+                }
+            }
+        });
+
+        quote! {
+            #[doc = #doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis enum #field_enum {
+                #(
+                    #[doc = ::core::concat!("The `", #names, "` field")]
+                    #variants,
+                )*
+            }
+
+            impl #field_enum {
+                /// All the fields, in declaration order
+                pub const ALL: [Self; #count] = [#(Self::#variants),*];
+
+                /// Return the name of the field
+                pub const fn name(&self) -> &'static str {
+                    match *self {
+                        #(Self::#variants => #names,)*
+                    }
+                }
+            }
+
+            impl ::core::fmt::Display for #field_enum {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    f.write_str(self.name())
+                }
+            }
+
+            impl ::core::str::FromStr for #field_enum {
+                type Err = ::getters2_core::UnknownField;
+
+                fn from_str(s: &str) -> ::core::result::Result<Self, Self::Err> {
+                    match s {
+                        #(#names => ::core::result::Result::Ok(Self::#variants),)*
+                        _ => ::core::result::Result::Err(::getters2_core::UnknownField),
+                    }
+                }
+            }
+
+            #[doc = #ref_doc]
+            #vis enum #field_ref #ref_impl_generics #where_clause {
+                #(
+                    #[doc = ::core::concat!("The `", #names, "` field")]
+                    #variants(&'getters2 #types),
+                )*
+                #ref_marker
+            }
+
+            #field_mut
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// Return an immutable reference to a field chosen at runtime
+                pub fn get<'getters2>(
+                    &'getters2 self,
+                    field: #field_enum,
+                ) -> #field_ref #ref_ty_generics {
+                    match field {
+                        #(#field_enum::#variants => #field_ref::#variants(&self.#members),)*
+                    }
+                }
+            }
+        }
+    }
+
+    /// A struct of references to some fields of the input, named `view`, with a field named
+    /// after each of the given fields' accessors. A marker is added for generic parameters
+    /// which none of the fields use.
+    fn view(
         &self,
-        field: &GettersField,
-        index: usize,
-        max: usize,
-        enum_ident: &Ident,
-        variant_ident: &Ident,
-        skip: bool,
-        skip_mutable: bool,
-        skip_clone: bool,
-        skip_deref: bool,
+        view: &Ident,
+        doc: &str,
+        fields: &[(Ident, &Type)],
+        mutable: bool,
     ) -> TokenStream2 {
-        let ty = &field.ty;
-        let immutable = !field.skip.is_present() && !skip;
-        let mutable = (field.mutable.is_present() || self.mutable.is_present())
-            && !field.skip_mutable.is_present()
-            && !skip_mutable;
-        let clone = (field.clone.is_present() || self.clone.is_present())
-            && !field.skip_clone.is_present()
-            && !skip_clone;
-        let deref = (field.deref.is_present() || self.deref.is_present())
-            && !field.skip_deref.is_present()
-            && !skip_deref;
-        let prefix = variant_ident.to_string().to_ascii_lowercase();
+        let ident = &self.ident;
+        let vis = &self.vis;
+        let (_, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, parse_quote!('getters2));
+        let (impl_generics, _, _) = generics.split_for_impl();
+        let mutability = mutable.then(|| quote!(mut));
+        let (names, types): (Vec<_>, Vec<_>) = fields.iter().cloned().unzip();
+        let docs = names
+            .iter()
+            .map(|n| format!("The `{}` field", n))
+            .collect::<Vec<_>>();
+        let marker = self
+            .view_marker(fields)
+            .then(|| quote!(_marker: ::core::marker::PhantomData<&'getters2 #ident #ty_generics>,));
 
-        let (immutable, maybe_mutable, maybe_clone, maybe_deref) =
-            if let Some(ident) = field.ident.as_ref() {
-                let ident_ref = format_ident!("{}_{}_ref", prefix, ident);
-                let ident_mut = format_ident!("{}_{}_mut", prefix, ident);
-                let ident_clone = format_ident!("{}_{}_clone", prefix, ident);
-                let ident_deref = format_ident!("{}_{}_deref", prefix, ident);
-                (
-                    immutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return an immutable reference to the field
-                            pub fn #ident_ref(&self) -> Option<&#ty> {
-                                if let #enum_ident::#variant_ident { #ident, .. } = self {
-                                    Some(#ident)
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                    mutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a mutable reference to the field
-                            pub fn #ident_mut(&mut self) -> Option<&mut #ty> {
-                                if let #enum_ident::#variant_ident { ref mut #ident, .. } = self {
-                                    Some(#ident)
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                    clone
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a clone of the field
-                            pub fn #ident_clone(&self) -> Option<#ty> {
-                                if let #enum_ident::#variant_ident { #ident, .. } = self {
-                                    Some(#ident.clone())
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                    deref
-                        .then_some(quote! {
+        quote! {
+            #[doc = #doc]
+            #vis struct #view #impl_generics #where_clause {
+                #(
+                    #[doc = #docs]
+                    pub #names: &'getters2 #mutability #types,
+                )*
+                #marker
+            }
+        }
+    }
+
+    /// The implementations of `getters2_core::HasField` and `HasFieldMut` for each field of a
+    /// struct with `has_field`, for the marker type named after its getters
+    fn has_field_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+
+        fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| self.generates(Access::HasField, None, f))
+            .map(|(i, f)| {
+                let ty = &f.ty;
+                let name = f.name(i, fields.len()).to_string();
+                let marker = quote!(::getters2_core::Field<{ ::getters2_core::field_id(#name) }>);
+                let member = if let Some(ident) = f.ident.as_ref() {
+                    Member::Named(ident.clone())
+                } else {
+                    Member::Unnamed(Index {
+                        index: i as u32,
+                        span: Span::call_site(),
+                    })
+                };
+                let has_field_mut = self.generates(Access::Mutable, None, f).then(|| {
+                    quote! {
+                        impl #impl_generics ::getters2_core::HasFieldMut<#marker>
+                            for #ident #ty_generics #where_clause
+                        {
                             #[inline(always)]
-                            /// Return a copy of the field
-                            pub fn #ident_deref(&self) -> Option<#ty> {
-                                if let #enum_ident::#variant_ident { #ident, .. } = self {
-                                    Some(*#ident)
-                                } else {
-                                    None
-                                }
+                            fn field_mut(&mut self) -> &mut #ty {
+                                &mut self.#member
                             }
-                        })
-                        .unwrap_or_default(),
-                )
+                        }
+                    }
+                });
+
+                quote! {
+                    impl #impl_generics ::getters2_core::HasField<#marker>
+                        for #ident #ty_generics #where_clause
+                    {
+                        type Type = #ty;
+
+                        #[inline(always)]
+                        fn field(&self) -> &#ty {
+                            &self.#member
+                        }
+                    }
+
+                    #has_field_mut
+                }
+            })
+            .collect()
+    }
+
+    /// The pin projections of a struct with fields marked `pin`, along with the items which
+    /// make them sound. The struct is only `Unpin` if its pinned fields are, and implementing
+    /// `Drop` for it, which could move a pinned field out of `&mut self`, conflicts with a
+    /// blanket implementation of a hidden trait.
+    fn pin_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        if !self.pinned() {
+            return TokenStream2::new();
+        }
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, parse_quote!('getters2));
+        let (origin_impl_generics, origin_ty_generics, origin_where_clause) =
+            generics.split_for_impl();
+        let origin = format_ident!("__Getters2Pinned{}", ident);
+        let mut unpin_generics = generics.clone();
+        unpin_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#origin #origin_ty_generics: ::core::marker::Unpin));
+        let (unpin_impl_generics, _, unpin_where_clause) = unpin_generics.split_for_impl();
+
+        let origin_fields = fields.iter().map(|f| {
+            let ty = &f.ty;
+
+            if f.pin.is_present() {
+                quote!(#ty)
             } else {
-                // Field with no ident, we generate a named method
-                let name = method_name(index, max);
-                let name_ref = format_ident!("{}_{}_ref", prefix, name);
-                let name_mut = format_ident!("{}_{}_mut", prefix, name);
-                let name_clone = format_ident!("{}_{}_clone", prefix, name);
-                let name_deref = format_ident!("{}_{}_deref", prefix, name);
-                let elements = tuple_elements(index, max);
-                let elements_mut = tuple_elements_mut(index, max);
-                let element = tuple_element_name(index);
+                quote!(::core::marker::PhantomData<*const #ty>)
+            }
+        });
+
+        let methods = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.skip.is_present())
+            .map(|(i, f)| {
+                let ty = &f.ty;
+                let base = f.name(i, fields.len());
+                let member = if let Some(ident) = f.ident.as_ref() {
+                    Member::Named(ident.clone())
+                } else {
+                    Member::Unnamed(Index {
+                        index: i as u32,
+                        span: Span::call_site(),
+                    })
+                };
+
+                if f.pin.is_present() {
+                    let name = format_ident!("{}_pin", base);
+
+                    quote! {
+                        #[inline(always)]
+                        /// Project a pinned reference to the struct to a pinned reference to the
+                        /// field
+                        pub fn #name(
+                            self: ::core::pin::Pin<&mut Self>,
+                        ) -> ::core::pin::Pin<&mut #ty> {
+                            // SAFETY: The field is structurally pinned: the struct is only
+                            // `Unpin` if the field is, and it does not implement `Drop`
+                            unsafe { ::core::pin::Pin::map_unchecked_mut(self, |s| &mut s.#member) }
+                        }
+                    }
+                } else {
+                    let name = format_ident!("{}_pin_unpinned", base);
+
+                    quote! {
+                        #[inline(always)]
+                        /// Project a pinned reference to the struct to a mutable reference to
+                        /// the field, which is not structurally pinned
+                        pub fn #name(self: ::core::pin::Pin<&mut Self>) -> &mut #ty {
+                            // SAFETY: The field is not structurally pinned, so it is never
+                            // treated as pinned
+                            unsafe { &mut ::core::pin::Pin::get_unchecked_mut(self).#member }
+                        }
+                    }
+                }
+            })
+            .collect::<TokenStream2>();
+
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #methods
+            }
+
+            const _: () = {
+                #[allow(dead_code)]
+                struct #origin #origin_impl_generics (
+                    ::core::marker::PhantomData<&'getters2 ()>,
+                    #(#origin_fields,)*
+                ) #origin_where_clause;
+
+                impl #unpin_impl_generics ::core::marker::Unpin for #ident #ty_generics
+                    #unpin_where_clause
+                {
+                }
+
+                trait PinnedStructMustNotImplementDrop {}
+
+                #[allow(drop_bounds)]
+                impl<T: ::core::ops::Drop> PinnedStructMustNotImplementDrop for T {}
+
+                impl #impl_generics PinnedStructMustNotImplementDrop for #ident #ty_generics
+                    #where_clause
+                {
+                }
+            };
+        }
+    }
+
+    /// Whether a view of the given fields needs a marker, because some generic parameter of
+    /// the input is not used by any of their types
+    fn view_marker(&self, fields: &[(Ident, &Type)]) -> bool {
+        self.generics.params.iter().any(|p| {
+            let param = match p {
+                GenericParam::Type(t) => &t.ident,
+                GenericParam::Lifetime(l) => &l.lifetime.ident,
+                GenericParam::Const(c) => &c.ident,
+            };
+
+            !fields
+                .iter()
+                .any(|(_, ty)| mentions_ident(ty.to_token_stream(), param))
+        })
+    }
+
+    /// The constants generated by `layout`, with the offset and size of each field and the
+    /// number of fields of the struct
+    fn layout_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        if !self.layout.is_present() {
+            return TokenStream2::new();
+        }
+
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let count = fields.len();
+        let constants = fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.skip_layout.is_present())
+            .map(|(i, f)| {
+                let ty = &f.ty;
+                let name = f.name(i, fields.len()).to_string().to_ascii_uppercase();
+                let offset = format_ident!("{}_OFFSET", name, span = f.span());
+                let size = format_ident!("{}_SIZE", name, span = f.span());
+                let member = if let Some(ident) = f.ident.as_ref() {
+                    Member::Named(ident.clone())
+                } else {
+                    Member::Unnamed(Index {
+                        index: i as u32,
+                        span: Span::call_site(),
+                    })
+                };
+
+                quote! {
+                    /// The offset of the field in bytes from the start of the struct
+                    pub const #offset: ::core::primitive::usize =
+                        ::core::mem::offset_of!(Self, #member);
+                    /// The size of the field in bytes
+                    pub const #size: ::core::primitive::usize = ::core::mem::size_of::<#ty>();
+                }
+            })
+            .collect::<TokenStream2>();
+
+        quote! {
+            impl #impl_generics #ident #ty_generics #where_clause {
+                #constants
+                /// The number of fields of the struct
+                pub const FIELD_COUNT: ::core::primitive::usize = #count;
+            }
+        }
+    }
+
+    /// The views of a struct generated by `split`, borrowing the fields with immutable and
+    /// mutable getters respectively, and the `split` and `split_mut` methods returning them
+    fn split_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, parse_quote!('getters2));
+        let (_, view_generics, _) = generics.split_for_impl();
+
+        [(false, Access::Ref, "Ref"), (true, Access::Mutable, "Mut")]
+            .into_iter()
+            .map(|(mutable, access, suffix)| {
+                let (view_fields, members): (Vec<_>, Vec<_>) = fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| self.generates(access, None, f))
+                    .map(|(i, f)| {
+                        let member = if let Some(ident) = f.ident.as_ref() {
+                            Member::Named(ident.clone())
+                        } else {
+                            Member::Unnamed(Index {
+                                index: i as u32,
+                                span: Span::call_site(),
+                            })
+                        };
+
+                        ((f.name(i, fields.len()), &f.ty), member)
+                    })
+                    .unzip();
+
+                if view_fields.is_empty() {
+                    return TokenStream2::new();
+                }
+
+                let view_ident = format_ident!("{}{}", ident, suffix);
+                let names = view_fields.iter().map(|(n, _)| n);
+                let (method, receiver, doc, method_doc) = if mutable {
+                    (
+                        format_ident!("split_mut"),
+                        quote!(&'getters2 mut self),
+                        format!("Mutable references to the fields of [`{}`]", ident),
+                        "Borrow the fields mutably at once, so they can be used independently",
+                    )
+                } else {
+                    (
+                        format_ident!("split"),
+                        quote!(&'getters2 self),
+                        format!("Immutable references to the fields of [`{}`]", ident),
+                        "Borrow the fields immutably at once",
+                    )
+                };
+                let mutability = mutable.then(|| quote!(mut));
+                let view = self.view(&view_ident, &doc, &view_fields, mutable);
+                let marker = self
+                    .view_marker(&view_fields)
+                    .then(|| quote!(_marker: ::core::marker::PhantomData,));
+
+                quote! {
+                    #view
+
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        #[doc = #method_doc]
+                        pub fn #method<'getters2>(#receiver) -> #view_ident #view_generics {
+                            #view_ident {
+                                #(#names: &#mutability self.#members,)*
+                                #marker
+                            }
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The views of each variant of an enum generated by `split`, like `split_struct`, and
+    /// the `split_x` and `split_x_mut` methods returning them if the enum is the variant
+    fn split_enum(&self, variants: &[&GettersVariant]) -> TokenStream2 {
+        let ident = &self.ident;
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let mut generics = self.generics.clone();
+        generics.params.insert(0, parse_quote!('getters2));
+        let (_, view_generics, _) = generics.split_for_impl();
+
+        variants
+            .iter()
+            .filter(|v| !v.fields.is_empty())
+            .flat_map(|v| {
+                [(false, Access::Ref, "Ref"), (true, Access::Mutable, "Mut")]
+                    .into_iter()
+                    .map(move |(mutable, access, suffix)| (v, mutable, access, suffix))
+            })
+            .map(|(v, mutable, access, suffix)| {
+                let variant_ident = &v.ident;
+                let max = v.fields.len();
+                let included = v
+                    .fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| self.generates(access, Some(v), f))
+                    .collect::<Vec<_>>();
+
+                if included.is_empty() {
+                    return TokenStream2::new();
+                }
+
+                let view_fields = included
+                    .iter()
+                    .map(|(i, f)| (f.name(*i, max), &f.ty))
+                    .collect::<Vec<_>>();
+                let names = view_fields.iter().map(|(n, _)| n);
+                let (pattern, bindings) = if v.fields.style.is_struct() {
+                    let idents = included
+                        .iter()
+                        .filter_map(|(_, f)| f.ident.as_ref())
+                        .collect::<Vec<_>>();
+
+                    let bindings = idents.iter().map(|i| (*i).clone()).collect::<Vec<_>>();
+
+                    (quote!({ #(#idents,)* .. }), bindings)
+                } else {
+                    let elements = (0..max).map(|i| {
+                        if included.iter().any(|(j, _)| *j == i) {
+                            tuple_element_name(i).to_token_stream()
+                        } else {
+                            quote!(_)
+                        }
+                    });
+                    let bindings = included
+                        .iter()
+                        .map(|(i, _)| tuple_element_name(*i))
+                        .collect::<Vec<_>>();
+
+                    (quote!((#(#elements),*)), bindings)
+                };
+                let view_ident = format_ident!("{}{}{}", ident, variant_ident, suffix);
+                let (method, receiver, doc, method_doc) = if mutable {
+                    (
+                        format_ident!("split_{}_mut", v.prefix()),
+                        quote!(&'getters2 mut self),
+                        format!(
+                            "Mutable references to the fields of [`{}::{}`]",
+                            ident, variant_ident
+                        ),
+                        "Borrow the fields of the variant mutably at once, if the enum is the \
+                         variant, so they can be used independently",
+                    )
+                } else {
+                    (
+                        format_ident!("split_{}", v.prefix()),
+                        quote!(&'getters2 self),
+                        format!(
+                            "Immutable references to the fields of [`{}::{}`]",
+                            ident, variant_ident
+                        ),
+                        "Borrow the fields of the variant immutably at once, if the enum is the \
+                         variant",
+                    )
+                };
+                let view = self.view(&view_ident, &doc, &view_fields, mutable);
+                let marker = self
+                    .view_marker(&view_fields)
+                    .then(|| quote!(_marker: ::core::marker::PhantomData,));
+
+                let (ret, body) = self.variant_return(
+                    v,
+                    &pattern,
+                    quote!(#view_ident #view_generics),
+                    quote! {
+                        #view_ident {
+                            #(#names: #bindings,)*
+                            #marker
+                        }
+                    },
+                );
+
+                quote! {
+                    #view
+
+                    impl #impl_generics #ident #ty_generics #where_clause {
+                        #[doc = #method_doc]
+                        pub fn #method<'getters2>(#receiver) -> #ret {
+                            #body
+                        }
+                    }
+                }
+            })
+            .collect()
+    }
+
+    /// The `variant_name` method of an enum, generated by both `reflect` and `kind`
+    fn variant_name(&self) -> TokenStream2 {
+        let actual = self.active_variant_name();
+
+        quote! {
+            /// Return the name of the active variant
+            pub fn variant_name(&self) -> &'static str {
+                #actual
+            }
+        }
+    }
 
-                (
-                    immutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return an immutable reference to the field
-                            pub fn #name_ref(&self) -> Option<&#ty> {
-                                if let #enum_ident::#variant_ident(#elements) = self {
-                                    Some(#element)
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                    mutable
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Retur a mutable reference to the field
-                            pub fn #name_mut(&mut self) -> Option<&mut #ty> {
-                                if let #enum_ident::#variant_ident(#elements_mut) = self {
-                                    Some(#element)
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                    clone
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a clone of the field
-                            pub fn #name_clone(&self) -> Option<#ty> {
-                                if let #enum_ident::#variant_ident(#elements) = self {
-                                    Some(#element.clone())
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                    deref
-                        .then_some(quote! {
-                            #[inline(always)]
-                            /// Return a copy of the field
-                            pub fn #name_deref(&self) -> Option<#ty> {
-                                if let #enum_ident::#variant_ident(#elements) = self {
-                                    Some(*#element)
-                                } else {
-                                    None
-                                }
-                            }
-                        })
-                        .unwrap_or_default(),
-                )
-            };
+    /// An expression evaluating to the name of the active variant of `self`. This matches on
+    /// `*self`, which binds nothing, so an enum without variants needs no arms.
+    fn active_variant_name(&self) -> TokenStream2 {
+        let variants = self.data.as_ref().take_enum().unwrap_or_default();
+        let variant_idents = variants.iter().map(|v| &v.ident);
+        let variant_names = variants.iter().map(|v| v.ident.to_string());
 
         quote! {
-            #immutable
-            #maybe_mutable
-            #maybe_clone
-            #maybe_deref
+            match *self {
+                #(Self::#variant_idents { .. } => #variant_names,)*
+            }
         }
     }
 
-    fn methods_struct(&self, fields: &Fields<&GettersField>) -> TokenStream2 {
-        fields
+    /// The fieldless companion enum of an enum generated by `kind`, and the `kind`,
+    /// `variant_name` and `variant_index` methods and `VARIANT_NAMES` constant describing the
+    /// active variant
+    fn kind_enum(&self, variants: &[&GettersVariant]) -> TokenStream2 {
+        if !self.kind.is_present() {
+            return TokenStream2::new();
+        }
+
+        let ident = &self.ident;
+        let vis = &self.vis;
+        let kind = format_ident!("{}Kind", ident);
+        let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
+        let variant_idents = variants.iter().map(|v| &v.ident).collect::<Vec<_>>();
+        let variant_names = variant_idents
             .iter()
-            .enumerate()
-            .map(|(i, f)| self.method_field(f, i, fields.len()))
-            .collect::<TokenStream2>()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        let indices = 0..variants.len();
+        let doc = format!("The variants of [`{}`], without their fields", ident);
+        let variant_docs = variant_names.iter().map(|v| format!("The `{}` variant", v));
+        let variant_name = self.variant_name();
+
+        quote! {
+            #[doc = #doc]
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+                ::core::hash::Hash,
+            )]
+            #vis enum #kind {
+                #(
+                    #[doc = #variant_docs]
+                    #variant_idents,
+                )*
+            }
+
+            impl #impl_generics #ident #ty_generics #where_clause {
+                /// The names of the variants, in the order they are declared
+                pub const VARIANT_NAMES: &'static [&'static ::core::primitive::str] =
+                    &[#(#variant_names),*];
+
+                /// Return the kind of the active variant, without its fields
+                pub fn kind(&self) -> #kind {
+                    match *self {
+                        #(Self::#variant_idents { .. } => #kind::#variant_idents,)*
+                    }
+                }
+
+                /// Return the index of the active variant in the order the variants are
+                /// declared
+                pub fn variant_index(&self) -> ::core::primitive::usize {
+                    match *self {
+                        #(Self::#variant_idents { .. } => #indices,)*
+                    }
+                }
+
+                #variant_name
+            }
+        }
     }
 
-    fn methods_enum(&self, variants: &[&GettersVariant]) -> TokenStream2 {
-        variants
+    /// Runtime reflection over the fields of an enum, addressing each variant field by the
+    /// same name as its accessors (e.g. `dog_name`)
+    fn reflect_enum(&self, variants: &[&GettersVariant]) -> TokenStream2 {
+        let (names, (arms, arms_mut)): (Vec<_>, (Vec<_>, Vec<_>)) = variants
             .iter()
-            .map(|v| {
+            .filter(|v| !v.skip_reflect.is_present())
+            .flat_map(|v| {
                 let variant_ident = &v.ident;
-
-                if let Some(discriminant) = v.discriminant.as_ref() {
-                    abort!(
-                        discriminant,
-                        "Getters cannot be derived for enums with discriminants"
-                    )
-                }
+                let prefix = v.prefix();
+                let max = v.fields.len();
 
                 v.fields
                     .iter()
                     .enumerate()
-                    .map(|(i, f)| {
-                        self.method_variant(
-                            f,
-                            i,
-                            v.fields.len(),
-                            &self.ident,
-                            variant_ident,
-                            v.skip.is_present(),
-                            v.skip_mutable.is_present(),
-                            v.skip_clone.is_present(),
-                            v.skip_deref.is_present(),
+                    .filter(|(_, f)| !f.skip_reflect.is_present())
+                    .map(move |(i, f)| {
+                        let name = format!("{}_{}", prefix, f.name(i, max));
+                        let (pattern, element) = if let Some(ident) = f.ident.as_ref() {
+                            (quote!({ #ident, .. }), ident.clone())
+                        } else {
+                            let elements = tuple_elements(i, max);
+                            (quote!((#elements)), tuple_element_name(i))
+                        };
+
+                        (
+                            name.clone(),
+                            (
+                                quote! {
+                                    #name => if let Self::#variant_ident #pattern = self {
+                                        ::core::option::Option::Some(
+                                            #element as &dyn ::core::any::Any
+                                        )
+                                    } else {
+                                        ::core::option::Option::None
+                                    },
+                                },
+                                quote! {
+                                    #name => if let Self::#variant_ident #pattern = self {
+                                        ::core::option::Option::Some(
+                                            #element as &mut dyn ::core::any::Any
+                                        )
+                                    } else {
+                                        ::core::option::Option::None
+                                    },
+                                },
+                            ),
                         )
                     })
-                    .collect::<TokenStream2>()
             })
-            .collect::<TokenStream2>()
+            .unzip();
+
+        // NOTE: With `kind`, `variant_name` is generated along with the kind instead
+        let variant_name = (!self.kind.is_present()).then(|| self.variant_name());
+
+        quote! {
+            #variant_name
+
+            /// Return the names of the variant fields which can be accessed by name
+            pub fn field_names() -> &'static [&'static str] {
+                &[#(#names),*]
+            }
+
+            /// Return an immutable reference to the variant field with the given name, if its
+            /// variant is active
+            pub fn get_field(
+                &self,
+                name: &str,
+            ) -> ::core::option::Option<&dyn ::core::any::Any> {
+                match name {
+                    #(#arms)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+
+            /// Return a mutable reference to the variant field with the given name, if its
+            /// variant is active
+            pub fn get_field_mut(
+                &mut self,
+                name: &str,
+            ) -> ::core::option::Option<&mut dyn ::core::any::Any> {
+                match name {
+                    #(#arms_mut)*
+                    _ => ::core::option::Option::None,
+                }
+            }
+        }
     }
 }
 
@@ -683,6 +4048,8 @@ impl ToTokens for GettersInput {
         let ident = &self.ident;
         let (impl_generics, ty_generics, where_clause) = self.generics.split_for_impl();
 
+        let warnings = self.validate();
+
         let methods = if let Some(ref fields) = self.data.as_ref().take_struct() {
             self.methods_struct(fields)
         } else if let Some(ref variants) = self.data.as_ref().take_enum() {
@@ -694,15 +4061,365 @@ impl ToTokens for GettersInput {
             )
         };
 
+        let header = quote!(impl #impl_generics #ident #ty_generics #where_clause);
+        let flatten = self
+            .data
+            .as_ref()
+            .take_struct()
+            .map(|fields| self.flatten_struct(&fields, &header))
+            .unwrap_or_default();
+        let flatten_helper = self.flatten_helper(&methods);
+        // NOTE: Reflection lives in its own impl block so flattening does not delegate to it
+        let reflect = if !self.reflect.is_present() {
+            TokenStream2::new()
+        } else if let Some(ref fields) = self.data.as_ref().take_struct() {
+            let reflect = self.reflect_struct(fields);
+            let header = self.reflect_header();
+            quote!(#header { #reflect })
+        } else if let Some(ref variants) = self.data.as_ref().take_enum() {
+            let reflect = self.reflect_enum(variants);
+            let header = self.reflect_header();
+            quote!(#header { #reflect })
+        } else {
+            TokenStream2::new()
+        };
+
+        let field_enum = self
+            .data
+            .as_ref()
+            .take_struct()
+            .map(|fields| self.field_enum(&fields))
+            .unwrap_or_default();
+        let has_field = self
+            .data
+            .as_ref()
+            .take_struct()
+            .map(|fields| self.has_field_struct(&fields))
+            .unwrap_or_default();
+        let pin = self
+            .data
+            .as_ref()
+            .take_struct()
+            .map(|fields| self.pin_struct(&fields))
+            .unwrap_or_default();
+        let kind = self
+            .data
+            .as_ref()
+            .take_enum()
+            .map(|variants| self.kind_enum(&variants))
+            .unwrap_or_default();
+        let layout = self
+            .data
+            .as_ref()
+            .take_struct()
+            .map(|fields| self.layout_struct(&fields))
+            .unwrap_or_default();
+        let split = if !self.split.is_present() {
+            TokenStream2::new()
+        } else if let Some(ref fields) = self.data.as_ref().take_struct() {
+            self.split_struct(fields)
+        } else if let Some(ref variants) = self.data.as_ref().take_enum() {
+            self.split_enum(variants)
+        } else {
+            TokenStream2::new()
+        };
+
         tokens.extend(quote! {
-            impl #impl_generics #ident #ty_generics #where_clause {
+            #header {
+                #methods
+            }
+
+            #reflect
+            #field_enum
+            #split
+            #has_field
+            #pin
+            #layout
+            #kind
+
+            #flatten
+            #flatten_helper
+
+            #warnings
+        })
+    }
+}
+
+/// The input of the `__flatten` helper macro, invoked by the helper macro each derive
+/// generates for its type
+struct FlattenInput {
+    header: TokenStream2,
+    member: TokenStream2,
+    prefix: Vec<LitStr>,
+    signatures: Vec<TraitItemFn>,
+}
+
+impl Parse for FlattenInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let header;
+        let member;
+        let prefix;
+        let signatures;
+        braced!(header in input);
+        braced!(member in input);
+        braced!(prefix in input);
+        braced!(signatures in input);
+
+        let mut prefixes = Vec::new();
+        while !prefix.is_empty() {
+            prefixes.push(prefix.parse()?);
+        }
+
+        let mut fns = Vec::new();
+        while !signatures.is_empty() {
+            fns.push(signatures.parse()?);
+        }
+
+        Ok(Self {
+            header: header.parse()?,
+            member: member.parse()?,
+            prefix: prefixes,
+            signatures: fns,
+        })
+    }
+}
+
+impl ToTokens for FlattenInput {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let header = &self.header;
+        let member = &self.member;
+        let prefix = self
+            .prefix
+            .iter()
+            .map(|p| p.value())
+            .filter(|p| !p.is_empty())
+            .collect::<Vec<_>>();
+        // NOTE: The outermost prefix is spanned at the flattened field of the deriving type, so
+        // the delegating accessors lead to that field
+        let span = self
+            .prefix
+            .first()
+            .map(|p| p.span())
+            .unwrap_or_else(Span::call_site);
+
+        let methods = self
+            .signatures
+            .iter()
+            .map(|f| {
+                let mut sig = f.sig.clone();
+                let inner = &f.sig.ident;
+                // NOTE: `self` is hygienic, so both the receiver and the body must use a `self`
+                // created here rather than one passed through the helper macros
+                let receiver = if matches!(
+                    f.sig.receiver(),
+                    Some(r) if r.mutability.is_some()
+                ) {
+                    quote!(&mut self)
+                } else {
+                    quote!(&self)
+                };
+                // NOTE: Async accessors, like those of async locks, are delegated to in an async
+                // accessor awaiting the inner one
+                // NOTE: The arguments of accessors like setters are forwarded under names
+                // created here for the same reason
+                let (args, types): (Vec<_>, Vec<_>) = f
+                    .sig
+                    .inputs
+                    .iter()
+                    .filter_map(|a| match a {
+                        FnArg::Typed(a) => Some(a),
+                        FnArg::Receiver(_) => None,
+                    })
+                    .enumerate()
+                    .map(|(i, a)| {
+                        let arg = match &*a.pat {
+                            Pat::Ident(p) => Ident::new(&p.ident.to_string(), Span::call_site()),
+                            _ => format_ident!("arg{}", i),
+                        };
+
+                        (arg, &a.ty)
+                    })
+                    .unzip();
+                let call = if f.sig.asyncness.is_some() {
+                    quote!(self #member.#inner(#(#args),*).await)
+                } else {
+                    quote!(self #member.#inner(#(#args),*))
+                };
+                let name = prefix
+                    .iter()
+                    .cloned()
+                    .chain(std::iter::once(inner.to_string()))
+                    .collect::<Vec<_>>()
+                    .join("_");
+                sig.ident = Ident::new(&name, span);
+                sig.inputs = parse_quote!(#receiver, #(#args: #types),*);
+
+                quote! {
+                    #[inline(always)]
+                    /// Delegate to the accessor of the flattened field
+                    pub #sig {
+                        #call
+                    }
+                }
+            })
+            .collect::<TokenStream2>();
+
+        tokens.extend(quote! {
+            #header {
                 #methods
             }
         })
     }
 }
 
+/// The input of the `__flatten_helper` macro, which defines the helper macro of a type once
+/// the signatures of the accessors it delegates to each of its flattened fields have been
+/// collected. Each step takes the first pending field and invokes its helper macro, which
+/// hands its signatures back along with the rest of the input.
+struct FlattenHelperInput {
+    ident: Ident,
+    signatures: Vec<TraitItemFn>,
+    pending: Vec<(Path, LitStr)>,
+}
+
+impl Parse for FlattenHelperInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let ident;
+        let signatures;
+        let pending;
+        braced!(ident in input);
+        braced!(signatures in input);
+        braced!(pending in input);
+
+        let mut fns = Vec::new();
+        while !signatures.is_empty() {
+            fns.push(signatures.parse()?);
+        }
+
+        let mut fields = Vec::new();
+        while !pending.is_empty() {
+            let helper;
+            let prefix;
+            braced!(helper in pending);
+            braced!(prefix in pending);
+            fields.push((helper.parse()?, prefix.parse()?));
+        }
+
+        // NOTE: A collected field is followed by its prefix and the signatures of its helper
+        if !input.is_empty() {
+            let prefix;
+            let collected;
+            braced!(prefix in input);
+            braced!(collected in input);
+            let prefix = prefix.parse::<LitStr>()?.value();
+
+            while !collected.is_empty() {
+                let mut f = collected.parse::<TraitItemFn>()?;
+                let name = if prefix.is_empty() {
+                    f.sig.ident.to_string()
+                } else {
+                    format!("{}_{}", prefix, f.sig.ident)
+                };
+                f.sig.ident = Ident::new(&name, f.sig.ident.span());
+                fns.push(f);
+            }
+        }
+
+        Ok(Self {
+            ident: ident.parse()?,
+            signatures: fns,
+            pending: fields,
+        })
+    }
+}
+
+impl ToTokens for FlattenHelperInput {
+    fn to_tokens(&self, tokens: &mut TokenStream2) {
+        let ident = &self.ident;
+        let signatures = &self.signatures;
+
+        if let Some(((helper, prefix), rest)) = self.pending.split_first() {
+            let rest = rest
+                .iter()
+                .map(|(helper, prefix)| quote!({ #helper } { #prefix }));
+
+            tokens.extend(quote! {
+                #helper! {
+                    @signatures { { #ident } { #(#signatures)* } { #(#rest)* } { #prefix } }
+                }
+            });
+        } else {
+            tokens.extend(flatten_helper_macro(
+                ident,
+                &quote! {
+                    ::getters2::__flatten! {
+                        { $($header)* } { $($member)* } { $($prefix)* } { #(#signatures)* }
+                    }
+                },
+                &quote! {
+                    ::getters2::__flatten_helper! { $($state)* { #(#signatures)* } }
+                },
+            ));
+        }
+    }
+}
+
+/// The helper macro of a type, named after the type so that importing the type also imports
+/// it. Invoked with a header, member and prefix it expands to `delegate`, and invoked with
+/// `@signatures` and the state of `__flatten_helper` it expands to `collect`.
+fn flatten_helper_macro(
+    ident: &Ident,
+    delegate: &TokenStream2,
+    collect: &TokenStream2,
+) -> TokenStream2 {
+    // NOTE: The macro is defined in its own module, as re-exporting it next to the type
+    // would also import the type itself a second time
+    let module = format_ident!("__getters2_{}", ident);
+
+    quote! {
+        #[doc(hidden)]
+        #[allow(non_snake_case)]
+        mod #module {
+            #[allow(unused_macros)]
+            macro_rules! #ident {
+                ({ $($header:tt)* } { $($member:tt)* } { $($prefix:tt)* }) => {
+                    #delegate
+                };
+                (@signatures { $($state:tt)* }) => {
+                    #collect
+                };
+            }
+
+            #[allow(unused_imports)]
+            pub(crate) use #ident;
+        }
+
+        #[doc(hidden)]
+        #[allow(unused_imports)]
+        pub(crate) use #module::#ident;
+    }
+}
+
+/// The path of the helper macro generated for the type of a flattened field, which is the
+/// path of the type itself
+fn flatten_helper_path(ty: &Type) -> Path {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        abort!(ty, "Getters can only flatten fields whose type is a path")
+    };
+
+    if path
+        .segments
+        .iter()
+        .any(|s| !matches!(s.arguments, PathArguments::None))
+    {
+        abort!(ty, "Getters cannot flatten a field of generic type")
+    }
+
+    path.clone()
+}
+
 #[proc_macro_derive(Getters, attributes(getters))]
+#[proc_macro_error]
 #[allow(non_snake_case)]
 /// Derive macro for automatically implementing getter and setter patterns
 ///
@@ -808,7 +4525,23 @@ impl ToTokens for GettersInput {
 /// ```
 ///
 pub fn Getters(input: TokenStream) -> TokenStream {
-    let getters = match GettersInput::from_derive_input(&parse_macro_input!(input as DeriveInput)) {
+    let input = parse_macro_input!(input as DeriveInput);
+
+    // NOTE: darling reports unsupported shapes on the derive itself (or not at all, for
+    // unions), so we report them on the item instead
+    match &input.data {
+        SynData::Union(u) => abort!(
+            u.union_token,
+            "Getters can only be derived for structs and enums"
+        ),
+        SynData::Struct(s) if matches!(s.fields, syn::Fields::Unit) => abort!(
+            input.ident,
+            "Getters cannot be derived for unit structs, which have no fields"
+        ),
+        _ => {}
+    }
+
+    let getters = match GettersInput::from_derive_input(&input) {
         Ok(g) => g,
         Err(e) => {
             return TokenStream::from(e.write_errors());
@@ -822,6 +4555,37 @@ pub fn Getters(input: TokenStream) -> TokenStream {
     tokens.into()
 }
 
+#[proc_macro]
+#[proc_macro_error]
+#[doc(hidden)]
+/// Generate the delegating accessors of a flattened field. This macro is invoked by the
+/// helper macros generated by the derive and is not meant to be used directly.
+pub fn __flatten(input: TokenStream) -> TokenStream {
+    let flatten = parse_macro_input!(input as FlattenInput);
+
+    let mut tokens = TokenStream2::new();
+
+    flatten.to_tokens(&mut tokens);
+
+    tokens.into()
+}
+
+#[proc_macro]
+#[proc_macro_error]
+#[doc(hidden)]
+/// Define the helper macro of a type deriving `Getters`, after collecting the accessors it
+/// delegates to its flattened fields. This macro is invoked by the derive and by the helper
+/// macros it generates and is not meant to be used directly.
+pub fn __flatten_helper(input: TokenStream) -> TokenStream {
+    let helper = parse_macro_input!(input as FlattenHelperInput);
+
+    let mut tokens = TokenStream2::new();
+
+    helper.to_tokens(&mut tokens);
+
+    tokens.into()
+}
+
 const NUMERAL_TO_ORDINAL: [&str; 20] = [
     "first",
     "second",
@@ -846,6 +4610,213 @@ const NUMERAL_TO_ORDINAL: [&str; 20] = [
 ];
 const LAST: &str = "last";
 
+/// An expression cloning the field behind `field`, a reference to a field of type `ty`.
+/// A field which does not implement `Clone` is reported on the field's type, with a note on
+/// where the accessor was enabled (`origin`) and how to skip it.
+fn clone_field(ty: &Type, field: TokenStream2, accessor: &Ident, origin: &str) -> TokenStream2 {
+    let span = Span::call_site().located_at(ty.span());
+    let message = format!(
+        "the `{}` accessor requires `{{Self}}` to implement `Clone`",
+        accessor
+    );
+    let note = format!(
+        "the accessor is enabled by `#[getters(clone)]` on {}",
+        origin
+    );
+
+    quote_spanned! {span=>
+        {
+            #[diagnostic::on_unimplemented(
+                message = #message,
+                label = "this field does not implement `Clone`",
+                note = #note,
+                note = "add `#[getters(skip_clone)]` to the field to skip the accessor"
+            )]
+            trait GettersClone: ::core::marker::Sized {
+                fn getters_clone(&self) -> Self;
+            }
+
+            impl<T: ::core::clone::Clone> GettersClone for T {
+                #[inline(always)]
+                fn getters_clone(&self) -> Self {
+                    ::core::clone::Clone::clone(self)
+                }
+            }
+
+            let field = #field;
+            GettersClone::getters_clone(field)
+        }
+    }
+}
+
+/// An expression copying the field behind `field`, a reference to a field of type `ty`.
+/// A field which does not implement `Copy` is reported on the field's type, with a note on
+/// where the accessor was enabled (`origin`) and how to skip it.
+fn deref_field(ty: &Type, field: TokenStream2, accessor: &Ident, origin: &str) -> TokenStream2 {
+    copy_field(
+        ty,
+        field,
+        accessor,
+        &format!(
+            "the accessor is enabled by `#[getters(deref)]` on {}",
+            origin
+        ),
+        "add `#[getters(skip_deref)]` to the field to skip the accessor",
+    )
+}
+
+/// An expression copying the field behind `field`, a reference to a field of type `ty`.
+/// A field which does not implement `Copy` is reported on the field's type, with notes on
+/// why the accessor was generated and how to skip it.
+fn copy_field(
+    ty: &Type,
+    field: TokenStream2,
+    accessor: &Ident,
+    note: &str,
+    skip: &str,
+) -> TokenStream2 {
+    let span = Span::call_site().located_at(ty.span());
+    let message = format!(
+        "the `{}` accessor requires `{{Self}}` to implement `Copy`",
+        accessor
+    );
+
+    quote_spanned! {span=>
+        {
+            #[diagnostic::on_unimplemented(
+                message = #message,
+                label = "this field does not implement `Copy`",
+                note = #note,
+                note = #skip
+            )]
+            trait GettersDeref: ::core::marker::Sized {
+                fn getters_deref(&self) -> Self;
+            }
+
+            impl<T: ::core::marker::Copy> GettersDeref for T {
+                #[inline(always)]
+                fn getters_deref(&self) -> Self {
+                    *self
+                }
+            }
+
+            let field = #field;
+            GettersDeref::getters_deref(field)
+        }
+    }
+}
+
+/// The width in bits of a fixed-width integer type, or `None` for any other type
+fn integer_width(ty: &Type) -> Option<u32> {
+    let Type::Path(TypePath { qself: None, path }) = ty else {
+        return None;
+    };
+
+    match path.get_ident()?.to_string().as_str() {
+        "u8" | "i8" => Some(8),
+        "u16" | "i16" => Some(16),
+        "u32" | "i32" => Some(32),
+        "u64" | "i64" => Some(64),
+        "u128" | "i128" => Some(128),
+        _ => None,
+    }
+}
+
+/// The orderings of atomic loads and stores named by an `ordering` attribute
+fn atomic_ordering(ordering: Option<&LitStr>) -> (TokenStream2, TokenStream2) {
+    match ordering.map(|o| o.value()).as_deref() {
+        None | Some("SeqCst") => (quote!(SeqCst), quote!(SeqCst)),
+        Some("Relaxed") => (quote!(Relaxed), quote!(Relaxed)),
+        Some("AcqRel") => (quote!(Acquire), quote!(Release)),
+        Some(_) => abort!(
+            ordering,
+            "`ordering` must be one of `Relaxed`, `AcqRel` or `SeqCst`";
+            help = "`AcqRel` uses `Acquire` for loads and `Release` for stores"
+        ),
+    }
+}
+
+/// Convert a snake case name to upper camel case, for example `foo_bar` to `FooBar`
+fn upper_camel_case(name: &str) -> String {
+    name.trim_start_matches("r#")
+        .split('_')
+        .filter(|s| !s.is_empty())
+        .map(|s| {
+            let mut chars = s.chars();
+            chars
+                .next()
+                .map(|c| c.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+                .collect::<String>()
+        })
+        .collect()
+}
+
+/// Whether `tokens` (usually a type) contain `ident`, including as the name of a lifetime
+fn mentions_ident(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(i) => i == *ident,
+        TokenTree::Group(group) => mentions_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Whether `tokens` (usually a type) name any of the type parameters in `generics`
+fn mentions_type_params(tokens: TokenStream2, generics: &Generics) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => generics.type_params().any(|p| p.ident == ident),
+        TokenTree::Group(group) => mentions_type_params(group.stream(), generics),
+        _ => false,
+    })
+}
+
+/// Emit a warning at `span` on stable, where `proc_macro_error` can only emit errors, by
+/// using a deprecated constant
+fn warning(span: Span, message: &str) -> TokenStream2 {
+    let name = Ident::new("getters2_warning", span);
+
+    quote! {
+        const _: () = {
+            #[deprecated(note = #message)]
+            #[allow(non_upper_case_globals)]
+            const getters2_warning: () = ();
+            let _ = #name;
+        };
+    }
+}
+
+/// Report each kind of accessor which is both enabled and skipped by the same item
+fn validate_flags(flags: impl Fn(Access) -> (Flag, Flag)) {
+    for access in Access::ALL {
+        let (enable, skip) = flags(access);
+
+        if let Some(name) = access.enable() {
+            if enable.is_present() && skip.is_present() {
+                emit_error!(
+                    skip.span(),
+                    "`{}` contradicts `{}` on the same item",
+                    access.skip(),
+                    name
+                );
+            }
+        }
+    }
+}
+
+/// Abort if there are more tuple fields than we have names for. The last field of a struct is
+/// always named `last`, so structs support one more field than there are ordinals, while the
+/// fields of enum variants are also bound to `TUPLE_ELEMENTS` in patterns.
+fn check_tuple_fields<'a>(fields: impl IntoIterator<Item = &'a GettersField>, max: usize) {
+    if let Some(field) = fields.into_iter().filter(|f| f.ident.is_none()).nth(max) {
+        abort!(
+            field.ty,
+            "Getters supports at most {} tuple fields, use named fields instead",
+            max
+        )
+    }
+}
+
 /// Given an index (0, 1, 2, ...) return the name of the method
 /// (first, second, third, ..., last)
 fn method_name(i: usize, max: usize) -> Ident {
@@ -877,31 +4848,9 @@ fn tuple_elements(index: usize, max: usize) -> TokenStream2 {
             };
 
             if i == max - 1 {
-                quote!(ref #ident)
-            } else {
-                quote!(ref #ident,)
-            }
-        })
-        .collect::<TokenStream2>()
-}
-
-fn tuple_elements_mut(index: usize, max: usize) -> TokenStream2 {
-    (0..max)
-        .map(tuple_element_name)
-        .enumerate()
-        .map(|(i, n)| {
-            let ident = if i == index {
-                format_ident!("{}", n)
-            } else {
-                format_ident!("_{}", n)
-            };
-
-            let maybe_mut = if i == index { quote!(mut) } else { quote!() };
-
-            if i == max - 1 {
-                quote!(ref #maybe_mut #ident)
+                quote!(#ident)
             } else {
-                quote!(ref #maybe_mut #ident,)
+                quote!(#ident,)
             }
         })
         .collect::<TokenStream2>()
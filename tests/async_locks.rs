@@ -0,0 +1,134 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for async lock accessors, against stand-ins for async lock types with the same
+//! method names as those in async runtimes
+
+use getters2::Getters;
+use std::{
+    cell::{Ref, RefCell, RefMut},
+    future::Future,
+    pin::pin,
+    sync::{Arc, Mutex},
+    task::{Context, Poll, Wake, Waker},
+};
+
+/// A waker which does nothing, as the futures here never wait
+struct Noop;
+
+impl Wake for Noop {
+    fn wake(self: Arc<Self>) {}
+}
+
+/// Run a future which never waits to completion
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let waker = Waker::from(Arc::new(Noop));
+    let mut context = Context::from_waker(&waker);
+
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut context) {
+            return output;
+        }
+    }
+}
+
+mod sync {
+    use super::*;
+
+    /// A stand-in for an async mutex
+    pub struct Mutex<T>(RefCell<T>);
+
+    impl<T> Mutex<T> {
+        pub fn new(value: T) -> Self {
+            Self(RefCell::new(value))
+        }
+
+        pub async fn lock(&self) -> RefMut<'_, T> {
+            self.0.borrow_mut()
+        }
+    }
+
+    /// A stand-in for an async read-write lock
+    pub struct RwLock<T>(RefCell<T>);
+
+    impl<T> RwLock<T> {
+        pub fn new(value: T) -> Self {
+            Self(RefCell::new(value))
+        }
+
+        pub async fn read(&self) -> Ref<'_, T> {
+            self.0.borrow()
+        }
+
+        pub async fn write(&self) -> RefMut<'_, T> {
+            self.0.borrow_mut()
+        }
+    }
+}
+
+#[derive(Getters)]
+#[getters(
+    interior,
+    async_locks(mutex(runtime::sync::Mutex, sync::Mutex), rw_lock(sync::RwLock))
+)]
+struct State {
+    names: sync::Mutex<Vec<String>>,
+    config: sync::RwLock<i32>,
+    count: std::cell::Cell<i32>,
+    total: std::sync::Mutex<i32>,
+    hits: Mutex<i32>,
+}
+
+#[derive(Getters)]
+struct Service {
+    #[getters(flatten)]
+    state: State,
+}
+
+#[derive(Getters)]
+#[getters(interior, async_locks(mutex(sync::Mutex)))]
+enum StateEnum {
+    Foo(sync::Mutex<i32>),
+    Bar,
+}
+
+#[test]
+fn test_async_locks() {
+    let state = State {
+        names: sync::Mutex::new(vec![]),
+        config: sync::RwLock::new(1),
+        count: std::cell::Cell::new(2),
+        total: std::sync::Mutex::new(3),
+        hits: Mutex::new(4),
+    };
+
+    block_on(async {
+        state.lock_names().await.push("foo".to_string());
+        assert_eq!(state.lock_names().await.len(), 1);
+        *state.write_config().await += 1;
+        assert_eq!(*state.read_config().await, 2);
+    });
+    assert_eq!(state.count_get(), 2);
+    *state.lock_total().unwrap() += 1;
+    assert_eq!(
+        *state.lock_hits().unwrap() + *state.lock_total().unwrap(),
+        8
+    );
+
+    let service = Service { state };
+    block_on(async {
+        service.state_lock_names().await.push("bar".to_string());
+        *service.state_write_config().await += 1;
+        assert_eq!(*service.state_read_config().await, 3);
+        assert_eq!(service.state_lock_names().await.len(), 2);
+    });
+    assert_eq!(*service.state_lock_hits().unwrap(), 4);
+
+    let foo = StateEnum::Foo(sync::Mutex::new(1));
+    block_on(async {
+        *foo.lock_foo_first().await.unwrap() += 1;
+        assert_eq!(foo.lock_foo_first().await.map(|g| *g), Some(2));
+        assert!(StateEnum::Bar.lock_foo_first().await.is_none());
+    });
+}
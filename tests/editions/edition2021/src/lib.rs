@@ -0,0 +1,4 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+//! Checks code generated by getters2 under the 2021 edition. The tests live in `tests`.
@@ -0,0 +1,8 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+//! Derives on every supported shape under this crate's edition
+
+#![allow(clippy::disallowed_names)]
+
+include!("../../shapes.rs");
@@ -0,0 +1,156 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+// Derives on every supported shape, included by the test crate for each edition so the
+// generated code is checked under that edition's pattern and keyword rules.
+
+use std::cell::Cell;
+
+use getters2::Getters;
+use getters2_core::{Lens, Prism};
+
+#[derive(Getters)]
+#[getters(deref, clone, mutable, lens, reflect, split)]
+struct Named {
+    bar: i32,
+    #[getters(skip_deref)]
+    baz: String,
+}
+
+#[derive(Getters)]
+#[getters(deref, clone, mutable, lens, reflect, split)]
+struct Tuple(i32, #[getters(skip_deref)] String, i32);
+
+#[derive(Getters)]
+#[getters(deref, clone, mutable, lens, reflect)]
+struct Newtype(i32);
+
+#[derive(Getters)]
+#[getters(deref, clone, mutable, lens, reflect, split)]
+enum Enum {
+    Named {
+        bar: i32,
+        #[getters(skip_deref)]
+        baz: String,
+    },
+    Tuple(i32, #[getters(skip_deref)] String, i32),
+    Newtype(i32),
+    Unit,
+}
+
+#[derive(Getters)]
+#[getters(reborrow)]
+struct Reborrow<'a> {
+    shared: &'a i32,
+    unique: &'a mut i32,
+}
+
+#[derive(Getters)]
+#[getters(reborrow)]
+enum ReborrowEnum<'a> {
+    Named { shared: &'a i32, unique: &'a mut i32 },
+    Tuple(&'a i32, &'a mut i32),
+}
+
+#[derive(Getters)]
+#[getters(interior)]
+enum Interior {
+    Named { count: Cell<i32> },
+    Tuple(Cell<i32>, Cell<i32>),
+}
+
+#[test]
+fn test_struct_shapes() {
+    let mut named = Named {
+        bar: 1,
+        baz: "baz".to_string(),
+    };
+    *named.bar_mut() += 1;
+    assert_eq!(named.bar_deref(), 2);
+    assert_eq!(named.baz_clone(), "baz");
+    assert_eq!(Named::BAR.get(&named), &2);
+    assert!(named.get_field("baz").is_some());
+    named.split_mut().baz.push('!');
+    assert_eq!(named.split().baz, "baz!");
+
+    let mut tuple = Tuple(1, "tuple".to_string(), 3);
+    *tuple.last_mut() += 1;
+    assert_eq!(tuple.first_deref(), 1);
+    assert_eq!(tuple.second_clone(), "tuple");
+    assert_eq!(*Tuple::LAST.get_mut(&mut tuple), 4);
+    assert!(tuple.get_field_mut("second").is_some());
+    *tuple.split_mut().first += 1;
+    assert_eq!(tuple.split().first, &2);
+
+    let mut newtype = Newtype(1);
+    *newtype.first_mut() += 1;
+    assert_eq!(newtype.first_ref(), &2);
+    assert_eq!(Newtype::FIRST.get(&newtype), &2);
+}
+
+#[test]
+fn test_enum_shapes() {
+    let mut named = Enum::Named {
+        bar: 1,
+        baz: "baz".to_string(),
+    };
+    *named.named_bar_mut().unwrap() += 1;
+    assert_eq!(named.named_bar_deref(), Some(2));
+    assert_eq!(named.named_baz_clone().as_deref(), Some("baz"));
+    assert_eq!(Enum::NAMED_BAR.get(&named), Some(&2));
+    assert!(Enum::TUPLE_FIRST.get_mut(&mut named).is_none());
+    assert!(named.get_field_mut("named_baz").is_some());
+    named.split_named_mut().unwrap().baz.push('!');
+    assert_eq!(named.split_named().unwrap().baz, "baz!");
+
+    let mut tuple = Enum::Tuple(1, "tuple".to_string(), 3);
+    *tuple.tuple_last_mut().unwrap() += 1;
+    assert_eq!(tuple.tuple_first_deref(), Some(1));
+    assert_eq!(tuple.tuple_second_clone().as_deref(), Some("tuple"));
+    assert_eq!(Enum::TUPLE_LAST.get_mut(&mut tuple), Some(&mut 4));
+    assert!(tuple.get_field("tuple_second").is_some());
+    *tuple.split_tuple_mut().unwrap().first += 1;
+    assert_eq!(tuple.split_tuple().unwrap().first, &2);
+
+    let mut newtype = Enum::Newtype(1);
+    *newtype.newtype_first_mut().unwrap() += 1;
+    assert_eq!(newtype.newtype_first_ref(), Some(&2));
+    assert!(Enum::Unit.newtype_first_ref().is_none());
+}
+
+#[test]
+fn test_reborrow_shapes() {
+    let (shared, mut unique) = (1, 2);
+    let mut reborrow = Reborrow {
+        shared: &shared,
+        unique: &mut unique,
+    };
+    *reborrow.unique() += 1;
+    assert_eq!(reborrow.shared(), &1);
+
+    let mut named = ReborrowEnum::Named {
+        shared: &shared,
+        unique: &mut unique,
+    };
+    *named.named_unique().unwrap() += 1;
+    assert_eq!(named.named_shared(), Some(&1));
+
+    let mut tuple = ReborrowEnum::Tuple(&shared, &mut unique);
+    *tuple.tuple_last().unwrap() += 1;
+    assert_eq!(tuple.tuple_first(), Some(&1));
+    assert_eq!(unique, 5);
+}
+
+#[test]
+fn test_interior_shapes() {
+    let named = Interior::Named {
+        count: Cell::new(1),
+    };
+    assert_eq!(named.named_count_set(2), Some(()));
+    assert_eq!(named.named_count_get(), Some(2));
+
+    let tuple = Interior::Tuple(Cell::new(1), Cell::new(2));
+    assert_eq!(tuple.tuple_last_set(3), Some(()));
+    assert_eq!(tuple.tuple_last_get(), Some(3));
+    assert_eq!(tuple.named_count_get(), None);
+}
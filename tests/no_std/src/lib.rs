@@ -0,0 +1,91 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+//! Derives in a `no_std` crate which also shadows the prelude names generated code could
+//! otherwise resolve to, so the crate only builds if every generated path is absolute. The
+//! tests live in `tests`.
+
+#![no_std]
+#![deny(missing_docs)]
+
+use core::cell::Cell;
+use core::sync::atomic::AtomicU32;
+
+use getters2::Getters;
+
+#[allow(dead_code, missing_docs)]
+mod shadow {
+    pub struct Option;
+    pub struct Some;
+    pub struct None;
+    pub struct Result;
+    pub struct Ok;
+    pub struct Err;
+    pub trait Clone {}
+    pub trait Copy {}
+    pub trait Sized {}
+    pub trait Default {}
+}
+
+#[allow(unused_imports)]
+use shadow::*;
+
+#[allow(unused_macros)]
+macro_rules! concat {
+    ($($tt:tt)*) => {
+        compile_error!("generated code used the shadowed `concat!`")
+    };
+}
+
+/// A register block
+#[derive(Getters)]
+#[getters(deref, clone, mutable, lens, reflect, split, has_field)]
+#[getters(field_enum = "RegistersField")]
+pub struct Registers {
+    /// The control register
+    pub control: u32,
+    /// The status register
+    pub status: u8,
+}
+
+/// A register block with interior mutability
+#[derive(Getters)]
+#[getters(interior)]
+pub struct SharedRegisters {
+    /// The control register
+    pub control: Cell<u32>,
+    /// The status register
+    pub status: AtomicU32,
+}
+
+/// A command
+#[derive(Getters)]
+#[getters(deref, clone, mutable, lens, reflect, split)]
+pub enum Command {
+    /// Write a register
+    Write {
+        /// The address to write
+        address: u32,
+        /// The value to write
+        value: u32,
+    },
+    /// Read a register
+    Read(u32),
+    /// Reset the device
+    Reset,
+}
+
+/// A command borrowing its buffer
+#[derive(Getters)]
+#[getters(reborrow, interior)]
+pub enum Transfer<'a> {
+    /// Send a buffer
+    Send(&'a [u8]),
+    /// Receive into a buffer
+    Receive {
+        /// The buffer to receive into
+        buffer: &'a mut [u8],
+        /// The number of bytes received
+        received: Cell<usize>,
+    },
+}
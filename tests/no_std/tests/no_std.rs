@@ -0,0 +1,58 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+//! Tests for the accessors derived in the `no_std` crate
+
+use getters2_core::{field, HasField, Lens, Prism};
+use getters2_no_std::{Command, Registers, RegistersField, SharedRegisters, Transfer};
+
+#[test]
+fn test_struct() {
+    let mut registers = Registers {
+        control: 1,
+        status: 2,
+    };
+    *registers.control_mut() += 1;
+    assert_eq!(registers.control_deref(), 2);
+    assert_eq!(registers.status_clone(), 2);
+    assert_eq!(Registers::CONTROL.get(&registers), &2);
+    assert!(registers.get_field("status").is_some());
+    *registers.split_mut().status += 1;
+    assert_eq!(registers.split().status, &3);
+    assert_eq!("control".parse(), Ok(RegistersField::Control));
+    assert_eq!(HasField::<field!(status)>::field(&registers), &3);
+
+    let shared = SharedRegisters {
+        control: Default::default(),
+        status: Default::default(),
+    };
+    shared.control_set(4);
+    shared.store_status(5);
+    assert_eq!((shared.control_get(), shared.load_status()), (4, 5));
+}
+
+#[test]
+fn test_enum() {
+    let mut command = Command::Write {
+        address: 1,
+        value: 2,
+    };
+    *command.write_value_mut().unwrap() += 1;
+    assert_eq!(command.write_value_deref(), Some(3));
+    assert_eq!(command.read_first_clone(), None);
+    assert_eq!(Command::WRITE_ADDRESS.get(&command), Some(&1));
+    assert_eq!(command.variant_name(), "Write");
+    assert!(command.split_write().is_some());
+    assert!(Command::Reset.split_read().is_none());
+
+    let mut buffer = [0; 4];
+    let mut transfer = Transfer::Receive {
+        buffer: &mut buffer,
+        received: Default::default(),
+    };
+    transfer.receive_buffer().unwrap()[0] = 1;
+    transfer.receive_received_set(1);
+    assert_eq!(transfer.receive_received_get(), Some(1));
+    assert_eq!(Transfer::Send(&[1]).send_first(), Some(&[1][..]));
+    assert_eq!(buffer, [1, 0, 0, 0]);
+}
@@ -1,6 +1,8 @@
 // Copyright (C) 2023-2024 Rowan Hart
 // SPDX-License-Identifier: Apache-2.0
 
+//! Tests for the `Getters` derive macro
+
 #![allow(clippy::disallowed_names)]
 #![deny(missing_docs)]
 
@@ -40,6 +42,41 @@ fn test_struct_newtype() {
     assert_eq!(foo.first_clone(), 43);
 }
 
+#[derive(Getters)]
+#[allow(dead_code)]
+struct LongTuple(
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+    i32,
+);
+
+#[test]
+fn test_struct_tuple_long() {
+    let long = LongTuple(
+        1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20, 21,
+    );
+    assert_eq!(long.twentieth_ref(), &20);
+    assert_eq!(long.last_ref(), &21);
+}
+
 #[derive(Getters)]
 #[getters(deref, clone, mutable)]
 struct FooTuple(i32, i32, i32, i32, i32, i32);
@@ -278,6 +315,7 @@ fn test_enum_unit_with_discriminant() {
 
 #[derive(Getters)]
 #[getters(deref, clone, mutable)]
+/// A struct with all getters skipped
 pub struct Skip {
     #[getters(skip, skip_deref, skip_clone, skip_mutable)]
     _foo: i32,
@@ -285,13 +323,1061 @@ pub struct Skip {
 
 #[test]
 fn test_skip() {
-    // NOTE: THis is where we'd put our methods...if we had any!
-    // let mut foo = Skip { foo: 42 };
-    // assert_eq!(foo.foo_ref(), &42);
-    // assert_eq!(foo.foo_deref(), 42);
-    // assert_eq!(foo.foo_clone(), 42);
-    // *foo.foo_mut() = 43;
-    // assert_eq!(foo.foo_ref(), &43);
-    // assert_eq!(foo.foo_deref(), 43);
-    // assert_eq!(foo.foo_clone(), 43);
+    // NOTE: The skipped methods must not exist, which is checked by `tests/ui/skip.rs`
+    let _foo = Skip { _foo: 42 };
+}
+
+#[derive(Getters)]
+#[getters(deref, mutable)]
+struct FlattenInner {
+    bar: i32,
+    #[getters(skip_deref)]
+    baz: String,
+}
+
+#[derive(Getters)]
+struct FlattenMiddle {
+    #[getters(flatten)]
+    inner: FlattenInner,
+    #[getters(flatten, prefix = "")]
+    unprefixed: FooNewtype,
+}
+
+#[derive(Getters)]
+#[getters(mutable)]
+struct FlattenOuter {
+    #[getters(flatten, prefix = "mid")]
+    middle: FlattenMiddle,
+    qux: i32,
+}
+
+#[test]
+fn test_flatten() {
+    let mut foo = FlattenOuter {
+        middle: FlattenMiddle {
+            inner: FlattenInner {
+                bar: 42,
+                baz: "baz".to_string(),
+            },
+            unprefixed: FooNewtype(43),
+        },
+        qux: 44,
+    };
+    assert_eq!(foo.mid_inner_bar_ref(), &42);
+    assert_eq!(foo.mid_inner_bar_deref(), 42);
+    assert_eq!(foo.mid_inner_baz_ref(), "baz");
+    assert_eq!(foo.mid_first_ref(), &43);
+    assert_eq!(foo.mid_first_clone(), 43);
+    assert_eq!(foo.mid_inner_ref().bar_ref(), &42);
+    assert_eq!(foo.qux_ref(), &44);
+
+    *foo.mid_inner_bar_mut() = 45;
+    *foo.mid_first_mut() = 46;
+    foo.mid_inner_baz_mut().push('!');
+
+    assert_eq!(foo.middle_ref().inner_bar_ref(), &45);
+    assert_eq!(foo.middle_ref().first_ref(), &46);
+    assert_eq!(foo.mid_inner_baz_ref(), "baz!");
+}
+
+mod flatten_inner {
+    use getters2::Getters;
+
+    #[derive(Getters)]
+    #[getters(mutable)]
+    pub struct Inner {
+        pub bar: i32,
+        #[getters(bits(name = "low", range = "0..4"))]
+        pub flags: u8,
+        #[getters(interior)]
+        pub count: std::cell::Cell<u32>,
+    }
+}
+
+mod flatten_middle {
+    use crate::flatten_inner::Inner;
+    use getters2::Getters;
+
+    #[derive(Getters)]
+    pub struct Middle {
+        #[getters(flatten)]
+        pub inner: Inner,
+    }
+}
+
+mod flatten_outer {
+    // NOTE: The delegating accessors name the types of the accessors they delegate to
+    use crate::flatten_inner::Inner;
+    use crate::flatten_middle::Middle;
+    use getters2::Getters;
+
+    #[derive(Getters)]
+    pub struct Outer {
+        #[getters(flatten)]
+        pub middle: Middle,
+    }
+
+    impl Outer {
+        pub fn new(bar: i32) -> Self {
+            Self {
+                middle: Middle {
+                    inner: Inner {
+                        bar,
+                        flags: 0,
+                        count: Default::default(),
+                    },
+                },
+            }
+        }
+    }
+}
+
+#[test]
+fn test_flatten_modules() {
+    let mut outer = flatten_outer::Outer::new(42);
+    assert_eq!(outer.middle_inner_bar_ref(), &42);
+    *outer.middle_inner_bar_mut() += 1;
+    assert_eq!(outer.middle_inner_ref().bar_ref(), &43);
+
+    // NOTE: Accessors taking arguments have them forwarded
+    outer.middle_inner_set_low(5);
+    assert_eq!(outer.middle_inner_low(), 5);
+    outer.middle_inner_count_set(1);
+    assert_eq!(outer.middle_inner_count_get(), 1);
+}
+
+#[derive(Getters)]
+#[getters(lens)]
+struct LensNamed {
+    bar: i32,
+    #[getters(skip_lens)]
+    _baz: i32,
+}
+
+#[derive(Getters)]
+struct LensTuple(#[getters(lens)] i32, String);
+
+#[derive(Getters)]
+#[getters(lens)]
+enum LensEnum {
+    Foo {
+        bar: i32,
+    },
+    Bar(i32, String),
+    #[getters(skip_lens)]
+    Baz(i32),
+}
+
+#[test]
+fn test_lens() {
+    use getters2_core::{Lens, Prism};
+
+    let mut foo = LensNamed { bar: 42, _baz: 43 };
+    assert_eq!(LensNamed::BAR.name(), "bar");
+    assert_eq!(LensNamed::BAR.get(&foo), &42);
+    *LensNamed::BAR.get_mut(&mut foo) = 44;
+    assert_eq!(foo.bar_ref(), &44);
+
+    let mut foo = LensTuple(42, "foo".to_string());
+    assert_eq!(LensTuple::FIRST.get(&foo), &42);
+    assert_eq!(foo.last_ref(), "foo");
+    *LensTuple::FIRST.get_mut(&mut foo) = 43;
+    assert_eq!(foo.first_ref(), &43);
+
+    let mut foo = LensEnum::Foo { bar: 42 };
+    assert_eq!(LensEnum::FOO_BAR.name(), "Foo::bar");
+    assert_eq!(LensEnum::FOO_BAR.get(&foo), Some(&42));
+    assert_eq!(LensEnum::BAR_FIRST.get(&foo), None);
+    *LensEnum::FOO_BAR.get_mut(&mut foo).unwrap() = 43;
+    assert_eq!(foo.foo_bar_ref(), Some(&43));
+
+    let mut bar = LensEnum::Bar(42, "bar".to_string());
+    assert_eq!(LensEnum::BAR_LAST.get(&bar), Some(&"bar".to_string()));
+    LensEnum::BAR_LAST.get_mut(&mut bar).unwrap().push('!');
+    assert_eq!(bar.bar_last_ref(), Some(&"bar!".to_string()));
+    assert_eq!(LensEnum::FOO_BAR.get_mut(&mut bar), None);
+
+    let baz = LensEnum::Baz(42);
+    assert_eq!(baz.baz_first_ref(), Some(&42));
+}
+
+#[derive(Getters)]
+#[getters(reflect)]
+struct ReflectNamed {
+    bar: i32,
+    baz: String,
+    #[getters(skip_reflect)]
+    _qux: i32,
+}
+
+#[derive(Getters)]
+#[getters(reflect)]
+struct ReflectTuple(i32, String);
+
+#[derive(Getters)]
+#[getters(reflect)]
+enum ReflectEnum {
+    Foo {
+        bar: i32,
+        #[getters(skip_reflect)]
+        baz: i32,
+    },
+    Bar(i32, String),
+    #[getters(skip_reflect)]
+    Baz(i32),
+    Qux,
+}
+
+#[derive(Getters)]
+#[getters(reflect)]
+struct ReflectGeneric<'a, T> {
+    value: T,
+    #[getters(skip_reflect)]
+    label: &'a str,
+}
+
+#[derive(Getters)]
+#[getters(reflect)]
+enum ReflectGenericEnum<T> {
+    Some(T),
+    None,
+}
+
+#[test]
+fn test_reflect() {
+    let mut foo = ReflectNamed {
+        bar: 42,
+        baz: "baz".to_string(),
+        _qux: 43,
+    };
+    assert_eq!(ReflectNamed::field_names(), &["bar", "baz"]);
+    assert_eq!(
+        foo.get_field("bar").and_then(|f| f.downcast_ref::<i32>()),
+        Some(&42)
+    );
+    assert!(foo.get_field("_qux").is_none());
+    *foo.get_field_mut("bar")
+        .and_then(|f| f.downcast_mut::<i32>())
+        .unwrap() = 44;
+    assert_eq!(foo.bar_ref(), &44);
+    assert_eq!(foo.baz_ref(), "baz");
+
+    let foo = ReflectTuple(42, "foo".to_string());
+    assert_eq!(ReflectTuple::field_names(), &["first", "last"]);
+    assert_eq!(
+        foo.get_field("last")
+            .and_then(|f| f.downcast_ref::<String>()),
+        Some(&"foo".to_string())
+    );
+
+    let mut foo = ReflectEnum::Foo { bar: 42, baz: 43 };
+    assert_eq!(foo.variant_name(), "Foo");
+    assert_eq!(
+        ReflectEnum::field_names(),
+        &["foo_bar", "bar_first", "bar_last"]
+    );
+    assert_eq!(
+        foo.get_field("foo_bar")
+            .and_then(|f| f.downcast_ref::<i32>()),
+        Some(&42)
+    );
+    assert!(foo.get_field("bar_first").is_none());
+    assert!(foo.get_field("foo_baz").is_none());
+    assert_eq!(foo.foo_baz_ref(), Some(&43));
+    *foo.get_field_mut("foo_bar")
+        .and_then(|f| f.downcast_mut::<i32>())
+        .unwrap() = 44;
+    assert_eq!(foo.foo_bar_ref(), Some(&44));
+
+    let mut bar = ReflectEnum::Bar(42, "bar".to_string());
+    assert_eq!(bar.variant_name(), "Bar");
+    bar.get_field_mut("bar_last")
+        .and_then(|f| f.downcast_mut::<String>())
+        .unwrap()
+        .push('!');
+    assert_eq!(bar.bar_last_ref(), Some(&"bar!".to_string()));
+
+    assert_eq!(ReflectEnum::Baz(42).variant_name(), "Baz");
+    assert_eq!(ReflectEnum::Baz(42).baz_first_ref(), Some(&42));
+    assert_eq!(ReflectEnum::Qux.variant_name(), "Qux");
+
+    let mut generic = ReflectGeneric {
+        value: 42u8,
+        label: "foo",
+    };
+    assert_eq!(ReflectGeneric::<u8>::field_names(), &["value"]);
+    *generic
+        .get_field_mut("value")
+        .and_then(|f| f.downcast_mut::<u8>())
+        .unwrap() += 1;
+    assert_eq!(generic.value_ref(), &43);
+    assert_eq!(generic.label_ref(), &"foo");
+
+    let generic = ReflectGenericEnum::Some("foo");
+    assert_eq!(
+        generic
+            .get_field("some_first")
+            .and_then(|f| f.downcast_ref::<&str>()),
+        Some(&"foo")
+    );
+    assert_eq!(ReflectGenericEnum::<u8>::None.variant_name(), "None");
+}
+
+#[derive(Getters)]
+#[getters(mutable)]
+enum Inherit {
+    #[getters(clone, deref, skip_mutable)]
+    Foo {
+        bar: i32,
+        #[getters(skip_deref, mutable)]
+        baz: String,
+    },
+    #[getters(skip)]
+    Bar(#[getters(skip_mutable)] i32, String),
+    Baz,
+}
+
+#[test]
+fn test_inherit() {
+    let mut foo = Inherit::Foo {
+        bar: 42,
+        baz: "baz".to_string(),
+    };
+    assert_eq!(foo.foo_bar_ref(), Some(&42));
+    assert_eq!(foo.foo_bar_clone(), Some(42));
+    assert_eq!(foo.foo_bar_deref(), Some(42));
+    assert_eq!(foo.foo_baz_clone(), Some("baz".to_string()));
+    foo.foo_baz_mut().unwrap().push('!');
+    assert_eq!(foo.foo_baz_ref(), Some(&"baz!".to_string()));
+
+    let mut bar = Inherit::Bar(42, "bar".to_string());
+    bar.bar_last_mut().unwrap().push('!');
+    assert!(matches!(bar, Inherit::Bar(42, ref s) if s == "bar!"));
+    assert_eq!(Inherit::Baz.foo_bar_ref(), None);
+}
+
+#[derive(Getters)]
+#[getters(lens, reflect)]
+struct Rename {
+    #[getters(rename = "bar")]
+    foo: i32,
+    baz: i32,
+}
+
+#[derive(Getters)]
+enum RenameEnum {
+    FooBar(#[getters(rename = "value")] i32),
+    #[getters(rename = "foobar2")]
+    Foobar(i32),
+}
+
+#[test]
+fn test_rename() {
+    use getters2_core::Lens;
+
+    let rename = Rename { foo: 42, baz: 43 };
+    assert_eq!(rename.bar_ref(), &42);
+    assert_eq!(rename.baz_ref(), &43);
+    assert_eq!(Rename::BAR.get(&rename), &42);
+    assert_eq!(Rename::field_names(), &["bar", "baz"]);
+
+    assert_eq!(RenameEnum::FooBar(42).foobar_value_ref(), Some(&42));
+    assert_eq!(RenameEnum::Foobar(43).foobar2_first_ref(), Some(&43));
+    assert_eq!(RenameEnum::Foobar(43).foobar_value_ref(), None);
+}
+
+#[derive(Getters)]
+#[getters(clone, deref)]
+struct Generic<T, U>
+where
+    U: Default,
+{
+    foo: T,
+    bar: Vec<U>,
+    baz: i32,
+    #[getters(skip_deref, bound = "")]
+    qux: std::rc::Rc<T>,
+}
+
+#[derive(Getters)]
+#[getters(clone)]
+enum GenericEnum<T> {
+    Foo(T),
+    #[getters(skip_clone)]
+    Bar(std::cell::Cell<T>),
+}
+
+#[test]
+fn test_generic() {
+    let generic = Generic {
+        foo: "foo".to_string(),
+        bar: vec![42u8],
+        baz: 43,
+        qux: std::rc::Rc::new("qux".to_string()),
+    };
+    assert_eq!(generic.foo_clone(), "foo".to_string());
+    assert_eq!(generic.bar_clone(), vec![42]);
+    assert_eq!(generic.baz_deref(), 43);
+    assert_eq!(*generic.qux_clone(), "qux".to_string());
+
+    let foo = GenericEnum::Foo(String::from("foo"));
+    assert_eq!(foo.foo_first_clone(), Some("foo".to_string()));
+    let bar = GenericEnum::Bar(std::cell::Cell::new(String::new()));
+    assert_eq!(bar.foo_first_clone(), None);
+}
+
+#[derive(Getters)]
+#[getters(reborrow)]
+struct Reborrow<'a, 'b> {
+    data: &'a [u8],
+    rest: &'b mut Vec<u8>,
+    len: usize,
+}
+
+#[derive(Getters)]
+#[getters(reborrow)]
+enum ReborrowEnum<'a> {
+    Foo { data: &'a str },
+    Bar(&'a mut String),
+}
+
+#[test]
+fn test_reborrow() {
+    let mut rest = vec![3];
+    let data = {
+        let mut view = Reborrow {
+            data: &[1, 2],
+            rest: &mut rest,
+            len: 2,
+        };
+        view.rest().push(4);
+        assert_eq!(view.len_ref(), &2);
+        view.data()
+    };
+    assert_eq!(data, &[1, 2]);
+    assert_eq!(rest, vec![3, 4]);
+
+    let foo = {
+        let foo = ReborrowEnum::Foo { data: "foo" };
+        foo.foo_data()
+    };
+    assert_eq!(foo, Some("foo"));
+
+    let mut string = "bar".to_string();
+    let mut bar = ReborrowEnum::Bar(&mut string);
+    assert_eq!(bar.foo_data(), None);
+    bar.bar_first().unwrap().push('!');
+    assert_eq!(string, "bar!");
+}
+
+type Counter = std::sync::atomic::AtomicU64;
+
+#[derive(Getters)]
+#[getters(interior, ordering = "AcqRel")]
+struct Interior {
+    count: std::cell::Cell<u32>,
+    names: std::cell::RefCell<Vec<String>>,
+    state: std::sync::Mutex<String>,
+    config: std::sync::RwLock<i32>,
+    ready: std::sync::atomic::AtomicBool,
+    #[getters(interior_type = "AtomicU64", ordering = "Relaxed")]
+    total: Counter,
+    plain: i32,
+}
+
+#[derive(Getters)]
+#[getters(interior)]
+enum InteriorEnum {
+    Foo(std::cell::Cell<i32>),
+    Bar {
+        #[getters(skip_interior)]
+        bar: std::cell::Cell<i32>,
+    },
+}
+
+#[test]
+fn test_interior() {
+    let interior = Interior {
+        count: std::cell::Cell::new(1),
+        names: std::cell::RefCell::new(vec![]),
+        state: std::sync::Mutex::new("foo".to_string()),
+        config: std::sync::RwLock::new(2),
+        ready: std::sync::atomic::AtomicBool::new(false),
+        total: Counter::new(3),
+        plain: 4,
+    };
+    interior.count_set(interior.count_get() + 1);
+    assert_eq!(interior.count_get(), 2);
+    interior.borrow_mut_names().push("foo".to_string());
+    assert_eq!(interior.borrow_names().len(), 1);
+    interior.lock_state().unwrap().push('!');
+    assert_eq!(*interior.lock_state().unwrap(), "foo!");
+    *interior.write_config().unwrap() += 1;
+    assert_eq!(*interior.read_config().unwrap(), 3);
+    interior.store_ready(true);
+    assert!(interior.load_ready());
+    interior.store_total(interior.load_total() + 1);
+    assert_eq!(interior.load_total(), 4);
+    assert_eq!(interior.plain_ref(), &4);
+
+    let foo = InteriorEnum::Foo(std::cell::Cell::new(1));
+    assert_eq!(foo.foo_first_set(2), Some(()));
+    assert_eq!(foo.foo_first_get(), Some(2));
+    let bar = InteriorEnum::Bar {
+        bar: std::cell::Cell::new(1),
+    };
+    assert_eq!(bar.foo_first_get(), None);
+    assert_eq!(bar.bar_bar_ref().map(|b| b.get()), Some(1));
+}
+
+#[derive(Getters)]
+#[getters(field_enum = "FieldEnumField", mutable)]
+struct FieldEnum<T> {
+    #[getters(skip_mutable)]
+    x: f32,
+    some_name: T,
+    #[getters(skip)]
+    _hidden: T,
+}
+
+#[derive(Getters)]
+#[getters(field_enum = "FieldEnumMarkerField", mutable)]
+struct FieldEnumMarker<T> {
+    id: u64,
+    #[getters(skip_mutable)]
+    value: T,
+}
+
+#[derive(Getters)]
+#[getters(field_enum = "FieldEnumEmptyField", mutable)]
+struct FieldEnumEmpty {
+    #[getters(skip)]
+    _id: u64,
+}
+
+#[test]
+fn test_field_enum() {
+    let mut foo = FieldEnum {
+        x: 1.0,
+        some_name: "foo".to_string(),
+        _hidden: String::new(),
+    };
+    assert_eq!(
+        FieldEnumField::ALL,
+        [FieldEnumField::X, FieldEnumField::SomeName]
+    );
+    assert_eq!(FieldEnumField::SomeName.to_string(), "some_name");
+    assert_eq!("x".parse(), Ok(FieldEnumField::X));
+    assert_eq!(
+        "_hidden".parse::<FieldEnumField>(),
+        Err(getters2_core::UnknownField)
+    );
+
+    assert!(foo.get_mut(FieldEnumField::X).is_none());
+    for field in FieldEnumField::ALL {
+        if let Some(FieldEnumFieldMut::SomeName(name)) = foo.get_mut(field) {
+            name.push('!');
+        }
+    }
+    assert!(matches!(
+        foo.get(FieldEnumField::X),
+        FieldEnumFieldRef::X(&1.0)
+    ));
+    assert!(matches!(
+        foo.get(FieldEnumField::SomeName),
+        FieldEnumFieldRef::SomeName(name) if name == "foo!"
+    ));
+
+    let mut marker = FieldEnumMarker { id: 1, value: 'a' };
+    if let Some(FieldEnumMarkerFieldMut::Id(id)) = marker.get_mut(FieldEnumMarkerField::Id) {
+        *id += 1;
+    }
+    assert!(marker.get_mut(FieldEnumMarkerField::Value).is_none());
+    assert!(matches!(
+        marker.get(FieldEnumMarkerField::Value),
+        FieldEnumMarkerFieldRef::Value(&'a')
+    ));
+    assert_eq!(marker.id_ref(), &2);
+
+    assert!(FieldEnumEmptyField::ALL.is_empty());
+    assert_eq!(
+        "_id".parse::<FieldEnumEmptyField>(),
+        Err(getters2_core::UnknownField)
+    );
+    let _ = FieldEnumEmpty { _id: 1 };
+}
+
+#[derive(Getters)]
+#[getters(split, mutable)]
+struct Split<'a, T> {
+    x: f32,
+    #[getters(rename = "label")]
+    name: &'a str,
+    #[getters(skip_mutable)]
+    items: Vec<T>,
+}
+
+#[derive(Getters)]
+#[getters(split, mutable)]
+enum SplitEnum<T> {
+    Foo { bar: i32, baz: String },
+    Bar(T, #[getters(skip)] i32),
+    Baz,
+}
+
+#[test]
+fn test_split() {
+    let mut split = Split {
+        x: 1.0,
+        name: "foo",
+        items: vec![1],
+    };
+    // NOTE: `T` is only used by `items`, which has no mutable getter, so the view has a marker
+    let SplitMut { x, label, .. } = split.split_mut();
+    *x += 1.0;
+    *label = "bar";
+    let SplitRef { x, label, items } = split.split();
+    assert_eq!((x, label, items), (&2.0, &"bar", &vec![1]));
+
+    let mut foo = SplitEnum::<u8>::Foo {
+        bar: 42,
+        baz: "baz".to_string(),
+    };
+    let view = foo.split_foo_mut().unwrap();
+    *view.bar += 1;
+    view.baz.push('!');
+    let view = foo.split_foo().unwrap();
+    assert_eq!((view.bar, view.baz.as_str()), (&43, "baz!"));
+    assert!(foo.split_bar().is_none());
+
+    let mut bar = SplitEnum::Bar("bar".to_string(), 42);
+    bar.split_bar_mut().unwrap().first.push('!');
+    assert_eq!(bar.split_bar().unwrap().first, "bar!");
+    assert!(SplitEnum::<u8>::Baz.split_foo().is_none());
+}
+
+#[derive(Getters)]
+#[getters(has_field)]
+struct HasField {
+    id: u64,
+    #[getters(mutable)]
+    name: String,
+    #[getters(skip_has_field)]
+    _secret: String,
+}
+
+#[derive(Getters)]
+#[getters(has_field, mutable)]
+struct HasFieldTuple<T>(u64, T);
+
+#[test]
+fn test_has_field() {
+    use getters2_core::{field, HasFieldMut};
+
+    fn id(value: &dyn getters2_core::HasField<field!(id), Type = u64>) -> u64 {
+        *value.field()
+    }
+
+    fn rename<T: HasFieldMut<field!(name), Type = String>>(value: &mut T, name: &str) {
+        *value.field_mut() = name.to_string();
+    }
+
+    let mut foo = HasField {
+        id: 42,
+        name: "foo".to_string(),
+        _secret: String::new(),
+    };
+    assert_eq!(id(&foo), 42);
+    rename(&mut foo, "bar");
+    assert_eq!(foo.name_ref(), "bar");
+
+    let mut tuple = HasFieldTuple(43, 1.0);
+    *HasFieldMut::<field!(last)>::field_mut(&mut tuple) += 1.0;
+    assert_eq!(getters2_core::HasField::<field!(first)>::field(&tuple), &43);
+    assert_eq!(tuple.last_ref(), &2.0);
+}
+
+#[derive(Getters)]
+struct Counted<F> {
+    #[getters(pin)]
+    future: F,
+    polls: u32,
+}
+
+impl<F: std::future::Future> std::future::Future for Counted<F> {
+    type Output = (F::Output, u32);
+
+    fn poll(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Self::Output> {
+        *self.as_mut().polls_pin_unpinned() += 1;
+        let polls = *self.polls_ref();
+
+        self.future_pin().poll(cx).map(|output| (output, polls))
+    }
+}
+
+#[derive(Getters)]
+struct PinTuple<'a, F>(#[getters(pin)] F, &'a str)
+where
+    F: std::future::Future;
+
+#[test]
+fn test_pin() {
+    use std::future::Future;
+    use std::task::{Context, Poll, Waker};
+
+    fn assert_unpin<T: Unpin>(_: &T) {}
+
+    let mut counted = Counted {
+        future: std::future::ready(42),
+        polls: 0,
+    };
+    assert_unpin(&counted);
+
+    let mut cx = Context::from_waker(Waker::noop());
+    assert_eq!(
+        std::pin::Pin::new(&mut counted).poll(&mut cx),
+        Poll::Ready((42, 1))
+    );
+
+    let mut counted = Box::pin(Counted {
+        future: async { 43 },
+        polls: 0,
+    });
+    assert_eq!(counted.as_mut().poll(&mut cx), Poll::Ready((43, 1)));
+
+    let mut tuple = Box::pin(PinTuple(async { 44 }, "label"));
+    assert_eq!(*tuple.as_mut().last_pin_unpinned(), "label");
+    assert_eq!(tuple.as_mut().first_pin().poll(&mut cx), Poll::Ready(44));
+}
+
+#[derive(Getters)]
+#[repr(C, packed)]
+struct Packed {
+    tag: u8,
+    length: u32,
+    #[getters(rename = "payload")]
+    data: [u8; 3],
+    #[getters(skip)]
+    _padding: u8,
+}
+
+#[derive(Getters)]
+#[getters(deref)]
+#[repr(C, packed(2))]
+struct PackedTuple<T: Copy>(#[getters(skip_deref)] u8, T);
+
+#[test]
+fn test_packed() {
+    let mut packed = Packed {
+        tag: 1,
+        length: 2,
+        data: [3, 4, 5],
+        _padding: 0,
+    };
+    assert_eq!(packed.tag_get(), 1);
+    assert_eq!(packed.length_get(), 2);
+    packed.length_set(0x01020304);
+    packed.payload_set([6, 7, 8]);
+    assert_eq!(packed.length_get(), 0x01020304);
+    assert_eq!(packed.payload_get(), [6, 7, 8]);
+
+    let mut tuple = PackedTuple(1, 2u64);
+    tuple.last_set(3);
+    assert_eq!((tuple.first_get(), tuple.last_get()), (1, 3));
+    assert_eq!(tuple.last_deref(), 3);
+}
+
+#[derive(Getters)]
+struct Ipv4Header {
+    #[getters(
+        bits(name = "version", range = "4..8"),
+        bits(name = "ihl", range = "0..4")
+    )]
+    version_ihl: u8,
+    #[getters(
+        bits(name = "flags", range = "13..=15"),
+        bits(name = "offset", range = "0..13")
+    )]
+    flags_offset: i16,
+}
+
+#[derive(Getters)]
+#[repr(C, packed)]
+struct PackedRegister(
+    u8,
+    #[getters(
+        bits(name = "enable", range = "0..1"),
+        bits(name = "mode", range = "1..32")
+    )]
+    u32,
+);
+
+#[test]
+fn test_bits() {
+    let mut header = Ipv4Header {
+        version_ihl: 0x45,
+        flags_offset: 0,
+    };
+    assert_eq!((header.version(), header.ihl()), (4, 5));
+    header.set_version(6);
+    header.set_ihl(15);
+    assert_eq!(header.version_ihl_ref(), &0x6f);
+    header.set_flags(0b101);
+    header.set_offset(0x1fff);
+    assert_eq!(header.flags_offset_ref(), &-0x4001);
+    assert_eq!((header.flags(), header.offset()), (0b101, 0x1fff));
+
+    let mut register = PackedRegister(0, 0);
+    register.set_enable(1);
+    register.set_mode(0x7fff_ffff);
+    assert_eq!(register.last_get(), u32::MAX);
+    register.set_enable(0);
+    assert_eq!((register.enable(), register.mode()), (0, 0x7fff_ffff));
+}
+
+#[test]
+#[should_panic(expected = "16 does not fit in the 4 bits of `ihl`")]
+#[cfg(debug_assertions)]
+fn test_bits_overflow() {
+    let mut header = Ipv4Header {
+        version_ihl: 0,
+        flags_offset: 0,
+    };
+    header.set_ihl(16);
+}
+
+#[derive(Getters)]
+#[getters(mutable)]
+struct Frame {
+    #[getters(be_u32)]
+    len: [u8; 4],
+    #[getters(le_i16, rename = "delta")]
+    offset: [u8; 2],
+}
+
+#[derive(Getters)]
+#[repr(C, packed)]
+struct PackedFrame(u8, #[getters(be_u64)] [u8; 8]);
+
+#[test]
+fn test_byte_order() {
+    let mut frame = Frame {
+        len: [0, 0, 1, 2],
+        offset: [0xfe, 0xff],
+    };
+    assert_eq!(frame.len(), 0x0102);
+    assert_eq!(frame.delta(), -2);
+    frame.set_len(0x01020304);
+    frame.len_mut()[3] = 5;
+    assert_eq!(frame.len_ref(), &[1, 2, 3, 5]);
+    frame.set_delta(3);
+    assert_eq!(frame.delta_ref(), &[3, 0]);
+
+    let mut frame = PackedFrame(0, [0; 8]);
+    frame.set_last(1);
+    assert_eq!(frame.last_get(), [0, 0, 0, 0, 0, 0, 0, 1]);
+    assert_eq!(frame.last(), 1);
+}
+
+#[derive(Getters)]
+#[getters(layout)]
+#[repr(C)]
+struct Descriptor {
+    flags: u16,
+    len: u32,
+    #[getters(rename = "address")]
+    addr: u64,
+    #[getters(skip_layout)]
+    _reserved: [u8; 3],
+    #[getters(skip)]
+    checksum: u32,
+}
+
+#[derive(Getters)]
+#[getters(layout)]
+#[repr(C)]
+struct LayoutTuple<T>(u8, T);
+
+#[test]
+fn test_layout() {
+    assert_eq!(Descriptor::FIELD_COUNT, 5);
+    assert_eq!((Descriptor::FLAGS_OFFSET, Descriptor::FLAGS_SIZE), (0, 2));
+    assert_eq!((Descriptor::LEN_OFFSET, Descriptor::LEN_SIZE), (4, 4));
+    assert_eq!(
+        (Descriptor::ADDRESS_OFFSET, Descriptor::ADDRESS_SIZE),
+        (8, 8)
+    );
+    assert_eq!(
+        (Descriptor::CHECKSUM_OFFSET, Descriptor::CHECKSUM_SIZE),
+        (20, 4)
+    );
+
+    assert_eq!(LayoutTuple::<u32>::FIELD_COUNT, 2);
+    assert_eq!(LayoutTuple::<u32>::FIRST_OFFSET, 0);
+    assert_eq!(
+        (
+            LayoutTuple::<u32>::LAST_OFFSET,
+            LayoutTuple::<u32>::LAST_SIZE
+        ),
+        (4, 4)
+    );
+    assert_eq!(
+        (
+            LayoutTuple::<u64>::LAST_OFFSET,
+            LayoutTuple::<u64>::LAST_SIZE
+        ),
+        (8, 8)
+    );
+}
+
+#[derive(Getters)]
+#[getters(kind)]
+enum Event<T> {
+    Start { at: T },
+    Stop(T),
+    Reset,
+}
+
+#[derive(Getters)]
+#[getters(kind, reflect)]
+enum ReflectedEvent {
+    Start { at: u64 },
+    Reset,
+}
+
+#[derive(Getters)]
+#[getters(kind, reflect)]
+enum Never {}
+
+#[derive(Getters)]
+#[getters(reflect)]
+enum ReflectNever {}
+
+#[test]
+fn test_kind() {
+    let events = [Event::Start { at: 1 }, Event::Stop(2), Event::Reset];
+    assert_eq!(
+        events.iter().map(Event::kind).collect::<Vec<_>>(),
+        [EventKind::Start, EventKind::Stop, EventKind::Reset]
+    );
+    assert_eq!(
+        events.iter().map(Event::variant_index).collect::<Vec<_>>(),
+        [0, 1, 2]
+    );
+    assert_eq!(events[1].variant_name(), "Stop");
+    assert_eq!(Event::<u64>::VARIANT_NAMES, ["Start", "Stop", "Reset"]);
+    assert_eq!(events[0].start_at_ref(), Some(&1));
+
+    let kinds = events
+        .iter()
+        .map(Event::kind)
+        .collect::<std::collections::HashSet<_>>();
+    assert!(kinds.contains(&EventKind::Reset));
+
+    let event = ReflectedEvent::Start { at: 3 };
+    assert_eq!(event.kind(), ReflectedEventKind::Start);
+    assert_eq!(event.variant_name(), "Start");
+    assert_eq!(ReflectedEvent::Reset.variant_index(), 1);
+    assert!(Never::VARIANT_NAMES.is_empty());
+    assert!(Never::field_names().is_empty());
+    assert!(ReflectNever::field_names().is_empty());
+    assert_eq!(ReflectedEvent::field_names(), ["start_at"]);
+    assert_eq!(event.start_at_ref(), Some(&3));
+}
+
+#[derive(Getters)]
+#[getters(enum_return = "result", unwrap, mutable, clone)]
+enum Message {
+    Text {
+        body: String,
+    },
+    Ping(u32, u32),
+    #[getters(skip_mutable)]
+    Close {
+        code: u16,
+    },
+    Reset,
+}
+
+#[derive(Getters)]
+#[getters(enum_return = "result", reborrow, interior, split, mutable)]
+enum ResultAccessors<'a> {
+    Borrowed(&'a str, &'a mut i32),
+    Shared { count: std::cell::Cell<u32> },
+}
+
+#[derive(Getters)]
+#[getters(enum_return = "option")]
+enum OptionalMessage {
+    Text { body: String },
+    Reset,
+}
+
+#[test]
+fn test_enum_return() {
+    use getters2_core::VariantMismatch;
+
+    let mut text = Message::Text {
+        body: "hi".to_string(),
+    };
+    assert_eq!(text.text_body_ref().map(String::as_str), Ok("hi"));
+    assert_eq!(text.text_body_clone(), Ok("hi".to_string()));
+    let mismatch = text.ping_first_ref().unwrap_err();
+    assert_eq!((mismatch.expected(), mismatch.actual()), ("Ping", "Text"));
+    assert_eq!(
+        mismatch.to_string(),
+        "expected variant `Ping`, found `Text`"
+    );
+    assert_eq!(
+        Message::Reset.close_code_ref(),
+        Err(VariantMismatch::new("Close", "Reset"))
+    );
+
+    text.unwrap_text_body_mut().push('!');
+    assert_eq!(text.unwrap_text_body(), "hi!");
+    let mut ping = Message::Ping(1, 2);
+    *ping.ping_last_mut().unwrap() = 3;
+    assert_eq!(
+        (ping.unwrap_ping_first(), ping.unwrap_ping_last()),
+        (&1, &3)
+    );
+    assert_eq!(Message::Close { code: 1000 }.unwrap_close_code(), &1000);
+
+    let message = OptionalMessage::Text {
+        body: "hi".to_string(),
+    };
+    assert_eq!(message.text_body_ref().map(String::as_str), Some("hi"));
+    assert_eq!(OptionalMessage::Reset.text_body_ref(), None);
+
+    let mut value = 1;
+    let mut borrowed = ResultAccessors::Borrowed("foo", &mut value);
+    assert_eq!(borrowed.borrowed_first(), Ok("foo"));
+    *borrowed.borrowed_last().unwrap() += 1;
+    assert_eq!(
+        borrowed.shared_count_get(),
+        Err(VariantMismatch::new("Shared", "Borrowed"))
+    );
+    let view = borrowed.split_borrowed_mut().unwrap();
+    **view.last += 1;
+    assert!(borrowed.split_shared().is_err());
+    assert_eq!(value, 3);
+
+    let shared = ResultAccessors::Shared {
+        count: std::cell::Cell::new(1),
+    };
+    shared.shared_count_set(2).unwrap();
+    assert_eq!(shared.shared_count_get(), Ok(2));
+    assert_eq!(
+        shared.borrowed_first().unwrap_err().to_string(),
+        "expected variant `Borrowed`, found `Shared`"
+    );
+}
+
+#[test]
+#[should_panic(expected = "called `Message::unwrap_text_body` on a `Reset` value, expected `Text`")]
+fn test_unwrap_mismatch() {
+    Message::Reset.unwrap_text_body();
 }
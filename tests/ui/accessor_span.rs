@@ -0,0 +1,39 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+// Lints on accessor names are reported at the field the accessor was generated for, like
+// every other use of the accessor's name
+
+#![deny(non_snake_case)]
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(mutable)]
+struct Named {
+    #[allow(non_snake_case)]
+    Bar: i32,
+    #[allow(non_snake_case)]
+    #[getters(rename = "Quux")]
+    baz: i32,
+}
+
+#[derive(Getters)]
+struct Tuple(#[getters(rename = "First")] i32, u8);
+
+#[derive(Getters)]
+enum Animal {
+    Dog {
+        #[allow(non_snake_case)]
+        Name: String,
+    },
+}
+
+#[derive(Getters)]
+struct Outer {
+    #[allow(non_snake_case)]
+    #[getters(flatten, skip)]
+    Inner: Tuple,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Register {
+    #[getters(bits(name = "high", range = "4..12"))]
+    value: u8,
+}
+
+#[derive(Getters)]
+struct Flags {
+    #[getters(bits(name = "first", range = "0.."))]
+    value: u8,
+}
+
+#[derive(Getters)]
+struct Label {
+    #[getters(bits(name = "first", range = "0..1"))]
+    value: bool,
+}
+
+#[derive(Getters)]
+enum Message {
+    Data {
+        #[getters(bits(name = "tag", range = "0..1"))]
+        value: u8,
+    },
+}
+
+fn main() {}
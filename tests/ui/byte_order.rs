@@ -0,0 +1,24 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Length {
+    #[getters(be_u32)]
+    len: [u8; 2],
+}
+
+#[derive(Getters)]
+struct Number {
+    #[getters(le_u16)]
+    value: u16,
+}
+
+#[derive(Getters)]
+struct Conflict {
+    #[getters(be_u16, le_u16)]
+    value: [u8; 2],
+}
+
+fn main() {}
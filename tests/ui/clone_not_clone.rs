@@ -0,0 +1,17 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+struct Opaque;
+
+#[derive(Getters)]
+struct NotClone {
+    #[getters(clone)]
+    foo: Opaque,
+}
+
+#[derive(Getters)]
+struct NotCloneTuple(#[getters(clone)] Opaque);
+
+fn main() {}
@@ -0,0 +1,39 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Renamed {
+    #[getters(rename = "bar")]
+    foo: i32,
+    bar: i32,
+}
+
+#[derive(Getters)]
+#[getters(mutable, reflect)]
+struct Reflected {
+    get_field: i32,
+}
+
+#[derive(Getters)]
+#[getters(clone)]
+enum Prefixes {
+    FooBar { value: i32 },
+    Foobar { value: i32 },
+}
+
+#[derive(Getters)]
+enum Ordinals {
+    Foo(i32, i32),
+    #[getters(rename = "foo")]
+    Bar { first: i32 },
+}
+
+#[derive(Getters)]
+struct InvalidRename {
+    #[getters(rename = "not an identifier")]
+    foo: i32,
+}
+
+fn main() {}
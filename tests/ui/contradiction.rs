@@ -0,0 +1,25 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Contradiction {
+    #[getters(mutable, skip_mutable)]
+    foo: i32,
+    #[getters(clone)]
+    #[getters(skip_clone)]
+    bar: i32,
+}
+
+#[derive(Getters)]
+enum EnumContradiction {
+    #[getters(deref, skip_deref)]
+    Foo(i32),
+    Bar {
+        #[getters(lens, skip_lens)]
+        bar: i32,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(deref)]
+struct NotCopy {
+    foo: i32,
+    bar: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+enum Discriminant {
+    Foo = 1,
+    Bar = 2,
+}
+
+fn main() {}
@@ -0,0 +1,25 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(enum_return = "result", unwrap)]
+struct Point {
+    x: f32,
+    y: f32,
+}
+
+#[derive(Getters)]
+#[getters(enum_return = "panic")]
+enum Shape {
+    Circle(f32),
+}
+
+#[derive(Getters)]
+#[getters(unwrap, mutable)]
+enum Value {
+    Int { int: i64, int_mut: i64 },
+}
+
+fn main() {}
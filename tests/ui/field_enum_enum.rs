@@ -0,0 +1,12 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(field_enum = "AnimalField")]
+enum Animal {
+    Dog { name: String },
+}
+
+fn main() {}
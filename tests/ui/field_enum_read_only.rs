@@ -0,0 +1,15 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(field_enum = "SecretField")]
+struct Secret {
+    id: u64,
+}
+
+fn main() {
+    let mut secret = Secret { id: 1 };
+    secret.get_mut(SecretField::Id);
+}
@@ -0,0 +1,19 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Inner {
+    bar: i32,
+}
+
+#[derive(Getters)]
+enum Outer {
+    Foo {
+        #[getters(flatten)]
+        inner: Inner,
+    },
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Inner<T> {
+    bar: T,
+}
+
+#[derive(Getters)]
+struct Outer {
+    #[getters(flatten)]
+    inner: Inner<i32>,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Outer<'a> {
+    #[getters(flatten)]
+    inner: &'a i32,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(mutable)]
+enum Inherit {
+    #[getters(clone, skip_mutable)]
+    Foo { bar: i32 },
+    #[getters(skip)]
+    Bar(i32),
+}
+
+fn main() {
+    let mut foo = Inherit::Foo { bar: 42 };
+    foo.foo_bar_mut();
+    foo.bar_first_ref();
+    foo.bar_first_clone();
+}
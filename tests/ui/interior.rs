@@ -0,0 +1,35 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct NotInterior {
+    #[getters(interior)]
+    foo: Vec<u8>,
+}
+
+#[derive(Getters)]
+#[getters(interior)]
+struct UnknownInteriorType {
+    #[getters(interior_type = "Box")]
+    foo: Box<u8>,
+}
+
+#[derive(Getters)]
+#[getters(interior, ordering = "Acquire")]
+struct InvalidOrdering {
+    foo: std::sync::atomic::AtomicBool,
+}
+
+mod parking_lot {
+    pub struct Mutex<T>(T);
+}
+
+#[derive(Getters)]
+struct NotStdInterior {
+    #[getters(interior)]
+    foo: parking_lot::Mutex<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(layout)]
+enum Shape {
+    Circle(f32),
+    Square(f32),
+}
+
+#[derive(Getters)]
+#[getters(layout, lens)]
+struct Buffer {
+    len_size: u32,
+    len: u32,
+}
+
+fn main() {}
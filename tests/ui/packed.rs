@@ -0,0 +1,31 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(mutable, lens)]
+#[repr(C, packed)]
+struct Header {
+    tag: u8,
+    #[getters(clone)]
+    length: u32,
+}
+
+#[derive(Getters)]
+#[repr(packed)]
+struct Message {
+    tag: u8,
+    body: String,
+}
+
+#[derive(Getters)]
+#[getters(deref)]
+#[repr(packed)]
+struct Named {
+    #[getters(skip)]
+    tag: u8,
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,38 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use std::marker::PhantomPinned;
+
+use getters2::Getters;
+
+#[derive(Getters)]
+struct Pinned<T> {
+    #[getters(pin)]
+    pinned: T,
+    unpinned: PhantomPinned,
+}
+
+#[derive(Getters)]
+struct Dropped {
+    #[getters(pin)]
+    pinned: u8,
+}
+
+impl Drop for Dropped {
+    fn drop(&mut self) {}
+}
+
+#[derive(Getters)]
+enum Animal {
+    Dog {
+        #[getters(pin)]
+        name: String,
+    },
+}
+
+fn assert_unpin<T: Unpin>() {}
+
+fn main() {
+    assert_unpin::<Pinned<u8>>();
+    assert_unpin::<Pinned<PhantomPinned>>();
+}
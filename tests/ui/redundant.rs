@@ -0,0 +1,46 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+// NOTE: Redundant attributes are warnings, which we deny here so they are reported
+#![deny(deprecated)]
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(clone)]
+struct Redundant {
+    #[getters(clone)]
+    foo: i32,
+    #[getters(skip_mutable)]
+    bar: i32,
+    #[getters(skip_reflect)]
+    baz: i32,
+    #[getters(prefix = "qux")]
+    qux: i32,
+    #[getters(skip_layout)]
+    quux: i32,
+    #[getters(interior_type = "AtomicU32")]
+    corge: i32,
+    #[getters(skip_clone, bound = "")]
+    grault: i32,
+}
+
+#[derive(Getters)]
+#[getters(bound = "T: Clone")]
+struct RedundantBound<T> {
+    foo: T,
+}
+
+#[derive(Getters)]
+#[getters(deref)]
+enum EnumRedundant {
+    #[getters(deref, skip_lens)]
+    Foo(i32),
+    #[getters(skip)]
+    Bar {
+        #[getters(skip)]
+        bar: i32,
+    },
+}
+
+fn main() {}
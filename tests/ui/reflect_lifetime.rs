@@ -0,0 +1,20 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(reflect)]
+struct Borrowed<'a, T> {
+    value: T,
+    label: &'a str,
+}
+
+#[derive(Getters)]
+#[getters(reflect)]
+enum Token<'a> {
+    Word(&'a str),
+    End,
+}
+
+fn main() {}
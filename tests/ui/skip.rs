@@ -0,0 +1,17 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+#[getters(mutable)]
+struct Skip {
+    #[getters(skip, skip_mutable)]
+    bar: i32,
+}
+
+fn main() {
+    let mut skip = Skip { bar: 42 };
+    skip.bar_ref();
+    skip.bar_mut();
+}
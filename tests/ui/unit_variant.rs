@@ -0,0 +1,13 @@
+// Copyright (C) 2023-2024 Rowan Hart
+// SPDX-License-Identifier: Apache-2.0
+
+use getters2::Getters;
+
+#[derive(Getters)]
+enum UnitVariant {
+    Foo(i32),
+    #[getters(clone, skip_mutable)]
+    Bar,
+}
+
+fn main() {}